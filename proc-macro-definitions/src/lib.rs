@@ -14,8 +14,9 @@ use syn::{
 	spanned::Spanned,
 	token::Group,
 	visit_mut::{self, VisitMut},
-	Attribute, Error, Expr, ExprGroup, ExprLit, ExprPath, Field, Fields, Generics, Index, Item,
-	ItemEnum, ItemStruct, ItemUnion, Lit, LitInt, LitStr, Path, Result, Token, Variant, Visibility,
+	Attribute, Error, Expr, ExprGroup, ExprLit, ExprParen, ExprPath, ExprUnary, Field, Fields,
+	Generics, Index, Item, ItemEnum, ItemStruct, ItemUnion, Lit, LitInt, LitStr, Path, Result,
+	Token, Type, UnOp, Variant, Visibility,
 };
 use tap::Pipe;
 use vec_drain_where::VecDrainWhereExt;
@@ -23,11 +24,23 @@ use vec_drain_where::VecDrainWhereExt;
 mod kw {
 	use syn::custom_keyword;
 
+	custom_keyword!(alias);
+	custom_keyword!(by_value);
+	custom_keyword!(common);
 	custom_keyword!(faible);
+	custom_keyword!(flatten);
+	custom_keyword!(migrate);
 	custom_keyword!(name);
 	custom_keyword!(names);
 	custom_keyword!(nested_names);
 	custom_keyword!(no_weak_conversions);
+	custom_keyword!(other);
+	custom_keyword!(set_variant);
+	custom_keyword!(strong);
+	custom_keyword!(version);
+	custom_keyword!(version_field);
+	custom_keyword!(view);
+	custom_keyword!(weak);
 }
 
 #[proc_macro_attribute]
@@ -57,6 +70,12 @@ struct Args {
 	names: Expr,
 	nested_names: Expr,
 	no_weak_conversions: bool,
+	set_variant: bool,
+	weak: Option<Type>,
+	strong: Option<Type>,
+	version_field: Option<Expr>,
+	version: Option<Expr>,
+	migrate: Option<Expr>,
 }
 impl Default for Args {
 	fn default() -> Self {
@@ -66,6 +85,12 @@ impl Default for Args {
 			names: parse_quote_spanned! {Span::mixed_site()=> __faible__name_required},
 			nested_names: parse_quote_spanned! {Span::mixed_site()=> __faible__name_required},
 			no_weak_conversions: false,
+			set_variant: false,
+			weak: None,
+			strong: None,
+			version_field: None,
+			version: None,
+			migrate: None,
 		}
 	}
 }
@@ -116,6 +141,49 @@ fn args_parser(errors: &mut Vec<Error>) -> impl '_ + FnOnce(ParseStream) -> Resu
 						.parse::<kw::no_weak_conversions>()
 						.expect("unreachable");
 					args.no_weak_conversions = true;
+				} else if lookahead.peek(kw::set_variant) {
+					input.parse::<kw::set_variant>().expect("unreachable");
+					args.set_variant = true;
+				} else if lookahead.peek(kw::weak) {
+					input.parse::<kw::weak>().expect("unreachable");
+
+					input
+						.insist::<Token![=]>(errors)
+						.and_then(|_| input.insist(errors))
+						.map(Some)
+						.then_set(&mut args.weak);
+				} else if lookahead.peek(kw::strong) {
+					input.parse::<kw::strong>().expect("unreachable");
+
+					input
+						.insist::<Token![=]>(errors)
+						.and_then(|_| input.insist(errors))
+						.map(Some)
+						.then_set(&mut args.strong);
+				} else if lookahead.peek(kw::version_field) {
+					input.parse::<kw::version_field>().expect("unreachable");
+
+					input
+						.insist::<Token![=]>(errors)
+						.and_then(|_| input.insist(errors))
+						.map(Some)
+						.then_set(&mut args.version_field);
+				} else if lookahead.peek(kw::version) {
+					input.parse::<kw::version>().expect("unreachable");
+
+					input
+						.insist::<Token![=]>(errors)
+						.and_then(|_| input.insist(errors))
+						.map(Some)
+						.then_set(&mut args.version);
+				} else if lookahead.peek(kw::migrate) {
+					input.parse::<kw::migrate>().expect("unreachable");
+
+					input
+						.insist::<Token![=]>(errors)
+						.and_then(|_| input.insist(errors))
+						.map(Some)
+						.then_set(&mut args.migrate);
 				} else {
 					errors.push(lookahead.error());
 					input.parse::<TokenTree>().ok();
@@ -166,12 +234,29 @@ fn implement(args: Args, input: Item, errors: &mut Vec<Error>) -> TokenStream {
 		names: _,
 		nested_names: _,
 		no_weak_conversions,
+		set_variant: _,
+		weak,
+		strong,
+		version_field: _,
+		version: _,
+		migrate: _,
 	} = args;
 
 	let descriptor_type = descriptor_type(&descriptor, errors);
 
+	// Projections onto descriptors from other crates aren't normalised during coherence checks,
+	// so these can be spelled out to avoid conflicts with blanket implementations.
+	let weak = weak.map_or_else(
+		|| quote_spanned!(Span::mixed_site()=> <#descriptor_type as #faible::Descriptor>::Weak),
+		|weak| quote_spanned!(Span::mixed_site()=> #weak),
+	);
+	let strong = strong.map_or_else(
+		|| quote_spanned!(Span::mixed_site()=> <#descriptor_type as #faible::Descriptor>::Strong),
+		|strong| quote_spanned!(Span::mixed_site()=> #strong),
+	);
+
 	let fields = quote_spanned! {fields_span.resolved_at(Span::mixed_site())=>
-		(pub <#descriptor_type as #faible::Descriptor>::Weak)
+		(pub #weak)
 	};
 
 	let where_ = generics.where_clause.as_ref();
@@ -180,14 +265,14 @@ fn implement(args: Args, input: Item, errors: &mut Vec<Error>) -> TokenStream {
 	let weak_conversions = (!no_weak_conversions).then(|| {
 		quote_spanned! {Span::mixed_site()=>
 			#[automatically_derived]
-			impl #impl_generics core::convert::From<<#descriptor_type as #faible::Descriptor>::Weak> for #ident #type_generics #impl_where {
-				fn from(value: <#descriptor_type as #faible::Descriptor>::Weak) -> Self {
+			impl #impl_generics core::convert::From<#weak> for #ident #type_generics #impl_where {
+				fn from(value: #weak) -> Self {
 					Self(value)
 				}
 			}
 
 			#[automatically_derived]
-			impl #impl_generics core::convert::From<#ident #type_generics> for <#descriptor_type as #faible::Descriptor>::Weak #impl_where {
+			impl #impl_generics core::convert::From<#ident #type_generics> for #weak #impl_where {
 				fn from(value: #ident #type_generics) -> Self {
 					value.0
 				}
@@ -220,14 +305,14 @@ fn implement(args: Args, input: Item, errors: &mut Vec<Error>) -> TokenStream {
 		}
 
 		#[automatically_derived]
-		impl #impl_generics core::convert::From<<#descriptor_type as #faible::Descriptor>::Strong> for #ident #type_generics #impl_where {
-			fn from(value: <#descriptor_type as #faible::Descriptor>::Strong) -> Self {
+		impl #impl_generics core::convert::From<#strong> for #ident #type_generics #impl_where {
+			fn from(value: #strong) -> Self {
 				Self(#faible::Descriptor::strong_into_weak(&#descriptor, value))
 			}
 		}
 
 		#[automatically_derived]
-		impl #impl_generics core::convert::TryFrom<#ident #type_generics> for <#descriptor_type as #faible::Descriptor>::Strong #impl_where {
+		impl #impl_generics core::convert::TryFrom<#ident #type_generics> for #strong #impl_where {
 			type Error = <#descriptor_type as #faible::Descriptor>::Error;
 
 			fn try_from(value: #ident #type_generics) -> ::core::result::Result<Self, Self::Error> {
@@ -241,7 +326,7 @@ fn implement(args: Args, input: Item, errors: &mut Vec<Error>) -> TokenStream {
 		///
 		/// Automatically implemented by [faible](https://github.com/Tamschi/faible#readme).
 		#[automatically_derived]
-		unsafe impl #impl_generics #faible::View<<#descriptor_type as #faible::Descriptor>::Weak> for #ident #type_generics #impl_where {}
+		unsafe impl #impl_generics #faible::View<#weak> for #ident #type_generics #impl_where {}
 
 		#[automatically_derived]
 		impl #impl_generics #ident #type_generics #impl_where {
@@ -304,7 +389,14 @@ fn process_enum(enum_: ItemEnum, args: &Args, errors: &mut Vec<Error>) -> Proces
 		names,
 		nested_names,
 		no_weak_conversions: _,
+		set_variant,
+		weak: _,
+		strong: _,
+		version_field,
+		version,
+		migrate,
 	} = args;
+	reject_versioning([version_field, version, migrate], errors);
 	let ItemEnum {
 		attrs,
 		vis,
@@ -318,9 +410,45 @@ fn process_enum(enum_: ItemEnum, args: &Args, errors: &mut Vec<Error>) -> Proces
 	let ref_ty = Ident::new(&(ident.to_string() + "VariantRef"), Span::call_site());
 	let mut_ty = Ident::new(&(ident.to_string() + "VariantMut"), Span::call_site());
 	let owned_ty = Ident::new(&(ident.to_string() + "VariantOwned"), Span::call_site());
+	let kind_ty = Ident::new(&(ident.to_string() + "VariantKind"), Span::call_site());
 
 	let descriptor_type = descriptor_type(descriptor, errors);
 
+	// `#[faible(common)]` pseudo-variants declare fields shared by all variants, which are accessed directly.
+	let (common_variants, variants): (Vec<_>, Vec<_>) = variants.into_iter().partition(|variant| {
+		variant
+			.attrs
+			.iter()
+			.any(|attr| attr.path.is_ident("faible") && attr.parse_args::<kw::common>().is_ok())
+	});
+	let mut common_accessors = vec![];
+	for Variant {
+		ident,
+		fields,
+		discriminant,
+		..
+	} in common_variants
+	{
+		if let Some((eq, _)) = discriminant {
+			errors.push(Error::new(
+				eq.span,
+				"Common fields can't have a discriminant.",
+			));
+		}
+		if !matches!(fields, Fields::Named(_)) {
+			errors.push(Error::new(ident.span(), "Common fields must be named."));
+		}
+		common_accessors.extend(field_accessors(
+			fields,
+			descriptor,
+			faible,
+			nested_names,
+			&TokenStream::new(),
+			&TokenStream::new(),
+			errors,
+		));
+	}
+
 	let has_fields = variants.iter().any(|variant| !variant.fields.is_empty());
 
 	let mut owned_variants = vec![];
@@ -331,9 +459,24 @@ fn process_enum(enum_: ItemEnum, args: &Args, errors: &mut Vec<Error>) -> Proces
 	let mut variant_names = vec![];
 	let mut variant_idents = vec![];
 
+	let mut variant_views = vec![];
 	let mut variant_field_refs = vec![];
 	let mut variant_field_muts = vec![];
 
+	let mut other_variant = None;
+	// The other variant catches recognition errors, including in accessors of variants declared before it.
+	let other_kind = variants
+		.iter()
+		.find_map(|variant| take_args_from_attrs(&mut variant.attrs.clone(), &mut vec![]).other);
+	let mut classifiable = true;
+	let mut variant_accessors = vec![];
+
+	let mut kind_variants = vec![];
+	let mut kind_weak_names = vec![];
+	let mut kind_idents = vec![];
+	let root_descriptor = descriptor;
+	let default_descriptor = InnerArgs::default().descriptor;
+
 	for (
 		index,
 		Variant {
@@ -349,28 +492,70 @@ fn process_enum(enum_: ItemEnum, args: &Args, errors: &mut Vec<Error>) -> Proces
 			descriptor,
 			name,
 			names,
+			by_value: _, //TODO: Ensure unset.
+			other,
+			view: _,    //TODO: Ensure unset.
+			flatten: _, //TODO: Ensure unset.
+			aliases,
 		} = take_args_from_attrs(&mut attrs, errors);
+		reject_aliases(&aliases, errors);
 
-		variant_descriptors.push(descriptor);
-		variant_names.push(make_name(
-			"variant",
-			false,
-			ident.span(),
-			Some(&ident),
-			index,
-			discriminant.as_ref().map(|discriminant| &discriminant.1),
-			name.as_ref().unwrap_or(parent_names),
-			errors,
-		));
-		variant_idents.push(ident.clone());
+		if let Some(other) = other {
+			if other_variant.is_some() {
+				errors.push(Error::new(ident.span(), "Duplicate other variant."))
+			}
+			let member = match &fields {
+				Fields::Unit => None,
+				Fields::Named(named) if named.named.len() == 1 => named.named[0]
+					.ident
+					.clone()
+					.map(|ident| quote_spanned!(ident.span()=> #ident)),
+				Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => {
+					Some(quote_spanned!(unnamed.span()=> 0))
+				}
+				fields => {
+					errors.push(Error::new(
+						fields.span(),
+						"An other variant can have at most one field.",
+					));
+					None
+				}
+			};
+			other_variant = Some((ident.clone(), other, member));
+		} else {
+			classifiable &= quote_spanned!(Span::mixed_site()=> #descriptor).to_string()
+				== quote_spanned!(Span::mixed_site()=> #default_descriptor).to_string();
+			variant_descriptors.push(descriptor);
+			variant_names.push(make_name(
+				"variant",
+				false,
+				ident.span(),
+				Some(&ident),
+				index,
+				discriminant.as_ref().map(|discriminant| &discriminant.1),
+				name.as_ref().unwrap_or(parent_names),
+				errors,
+			));
+			variant_idents.push(ident.clone());
+		}
 
 		struct FieldInfo<'a> {
 			attrs: &'a mut Vec<Attribute>,
+			view: bool,
 			ident: Expr,
 			descriptor: Expr,
 			name: Expr,
 		}
 		let field_infos = match &mut fields {
+			_ if other.is_some() => {
+				for field in fields.iter_mut() {
+					reject_aliases(
+						&take_args_from_attrs(&mut field.attrs, errors).aliases,
+						errors,
+					);
+				}
+				vec![]
+			}
 			Fields::Unit => vec![],
 			Fields::Named(named) => named
 				.named
@@ -391,24 +576,36 @@ fn process_enum(enum_: ItemEnum, args: &Args, errors: &mut Vec<Error>) -> Proces
 						let InnerArgs {
 							descriptor,
 							name,
-							names, //TODO: Ensure empty.
+							names: _,    //TODO: Ensure empty.
+							by_value: _, //TODO: Ensure unset.
+							other: _,    //TODO: Ensure unset.
+							view,
+							flatten: _, //TODO: Ensure unset.
+							aliases,
 						} = take_args_from_attrs(attrs, errors);
+						reject_aliases(&aliases, errors);
 						FieldInfo {
 							attrs,
+							view,
 							ident: parse_quote_spanned!(ident.span().resolved_at(Span::mixed_site())=> #ident),
 							descriptor,
-							name: make_name(
-								"field",
-								true,
-								ident.span(),
-								ident.as_ref(),
-								index,
-								None,
-								name.as_ref()
-									.or_else(|| parent_names.as_ref())
-									.unwrap_or(nested_names),
-								errors,
-							),
+							// Views take the whole value, so they aren't named.
+							name: if view {
+								parse_quote_spanned!(Span::mixed_site()=> ())
+							} else {
+								make_name(
+									"field",
+									true,
+									ident.span(),
+									ident.as_ref(),
+									index,
+									None,
+									name.as_ref()
+										.or(parent_names.as_ref())
+										.unwrap_or(nested_names),
+									errors,
+								)
+							},
 						}
 					},
 				)
@@ -422,9 +619,9 @@ fn process_enum(enum_: ItemEnum, args: &Args, errors: &mut Vec<Error>) -> Proces
 						index,
 						Field {
 							attrs,
-							vis,
-							ident,
-							colon_token,
+							vis: _,
+							ident: _,
+							colon_token: _,
 							ty,
 						},
 					)| {
@@ -432,27 +629,39 @@ fn process_enum(enum_: ItemEnum, args: &Args, errors: &mut Vec<Error>) -> Proces
 						let InnerArgs {
 							descriptor,
 							name,
-							names, //TODO: Ensure empty.
+							names: _,    //TODO: Ensure empty.
+							by_value: _, //TODO: Ensure unset.
+							other: _,    //TODO: Ensure unset.
+							view,
+							flatten: _, //TODO: Ensure unset.
+							aliases,
 						} = take_args_from_attrs(attrs, errors);
+						reject_aliases(&aliases, errors);
 						FieldInfo {
 							attrs,
+							view,
 							ident: {
 								let index = Index::from(index);
 								parse_quote_spanned!(ty.span().resolved_at(Span::mixed_site())=> #index)
 							},
 							descriptor,
-							name: make_name(
-								"field",
-								true,
-								ty.span(),
-								None,
-								index,
-								None,
-								name.as_ref()
-									.or(parent_names.as_ref())
-									.unwrap_or(nested_names),
-								errors,
-							),
+							// Views take the whole value, so they aren't named.
+							name: if view {
+								parse_quote_spanned!(Span::mixed_site()=> ())
+							} else {
+								make_name(
+									"field",
+									true,
+									ty.span(),
+									None,
+									index,
+									None,
+									name.as_ref()
+										.or(parent_names.as_ref())
+										.unwrap_or(nested_names),
+									errors,
+								)
+							},
 						}
 					},
 				)
@@ -462,13 +671,38 @@ fn process_enum(enum_: ItemEnum, args: &Args, errors: &mut Vec<Error>) -> Proces
 		let mut field_refs = vec![];
 		let mut field_muts = vec![];
 
+		let view = field_infos.iter().any(|field_info| field_info.view);
+		if view && field_infos.len() != 1 {
+			errors.push(Error::new(
+				ident.span(),
+				"A view field must be its variant's only field.",
+			));
+		}
+
 		for FieldInfo {
 			attrs,
+			view,
 			ident,
 			descriptor,
 			name,
 		} in field_infos
 		{
+			if view {
+				field_refs.push(
+					quote_spanned! {ident.span().resolved_at(Span::mixed_site())=>
+						#(#attrs)*
+						#ident: #faible::View::from_ref(&self.0)
+					},
+				);
+				field_muts.push(
+					quote_spanned! {ident.span().resolved_at(Span::mixed_site())=>
+						#(#attrs)*
+						#ident: #faible::View::from_mut(&mut self.0)
+					},
+				);
+				continue;
+			}
+
 			field_refs.push(
 				quote_spanned! {ident.span().resolved_at(Span::mixed_site())=>
 					#(#attrs)*
@@ -490,6 +724,184 @@ fn process_enum(enum_: ItemEnum, args: &Args, errors: &mut Vec<Error>) -> Proces
 			);
 		}
 
+		variant_accessors.extend({
+			let name = ident.to_string();
+			let name = name.strip_prefix("r#").unwrap_or(&name).to_snake_case();
+			let is = Ident::new(&format!("is_{name}"), ident.span());
+			let as_ref = Ident::new(&format!("as_{name}"), ident.span());
+			let as_mut = Ident::new(&format!("as_{name}_mut"), ident.span());
+
+			let members = fields
+				.iter()
+				.enumerate()
+				.map(|(index, field)| match &field.ident {
+					Some(ident) => {
+						quote_spanned!(ident.span().resolved_at(Span::mixed_site())=> #ident)
+					}
+					None => {
+						let index = Index::from(index);
+						quote_spanned!(field.ty.span().resolved_at(Span::mixed_site())=> #index)
+					}
+				})
+				.collect::<Vec<_>>();
+			let bindings = (0..members.len())
+				.map(|index| Ident::new(&format!("field_{index}"), Span::mixed_site()))
+				.collect::<Vec<_>>();
+			let types = fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
+
+			let error = quote_spanned! {Span::mixed_site()=>
+				<#descriptor_type as #faible::Descriptor>::Error
+			};
+			let signatures = [
+				quote_spanned! {Span::mixed_site()=>
+					#vis fn #is(&self) -> ::core::result::Result<bool, #error>
+				},
+				quote_spanned! {Span::mixed_site()=>
+					#vis fn #as_ref<'access>(&'access self) -> ::core::result::Result<
+						::core::option::Option<(#(&'access #types),*)>,
+						#error,
+					>
+				},
+				quote_spanned! {Span::mixed_site()=>
+					#vis fn #as_mut<'access>(&'access mut self) -> ::core::result::Result<
+						::core::option::Option<(#(&'access mut #types),*)>,
+						#error,
+					>
+				},
+			];
+			let [is_signature, as_ref_signature, as_mut_signature] = &signatures;
+
+			// Views borrow the weak value, which can't be done beside the strong one.
+			if other.is_some() || view {
+				vec![
+					quote_spanned! {Span::mixed_site()=>
+						#is_signature {
+							Ok(matches!(self.as_variant()?, #ref_ty::#ident { .. }))
+						}
+					},
+					quote_spanned! {Span::mixed_site()=>
+						#as_ref_signature {
+							#[allow(unreachable_patterns)]
+							Ok(match self.as_variant()? {
+								#ref_ty::#ident { #(#members: #bindings),* } => ::core::option::Option::Some((#(#bindings),*)),
+								_ => ::core::option::Option::None,
+							})
+						}
+					},
+					quote_spanned! {Span::mixed_site()=>
+						#as_mut_signature {
+							#[allow(unreachable_patterns)]
+							Ok(match self.as_variant_mut()? {
+								#mut_ty::#ident { #(#members: #bindings),* } => ::core::option::Option::Some((#(#bindings),*)),
+								_ => ::core::option::Option::None,
+							})
+						}
+					},
+				]
+			} else {
+				let descriptor = variant_descriptors.last().expect("unreachable");
+				let name = variant_names.last().expect("unreachable");
+				let probing = quote_spanned! {Span::mixed_site()=>
+					<_ as #faible::VariantFilter<'_, _, #error, _>>::probing(descriptor, strong, #name)
+				};
+				let variant_ref = quote_spanned! {Span::mixed_site()=>
+					(|| -> ::core::result::Result<_, #error> {
+						Ok(#ref_ty::#ident {
+							#(#field_refs,)*
+						})
+					})()
+				};
+				// With probing, an earlier variant may take precedence or make this one ambiguous,
+				// so a resolving variant is confirmed through `variant`.
+				let selected = quote_spanned! {Span::mixed_site()=>
+					self.variant()? == #kind_ty::#ident
+				};
+				let (caught_is, caught_as) = (
+					other_kind.map(|_| quote_spanned!(Span::mixed_site()=> Ok(false))),
+					other_kind.map(|_| quote_spanned!(Span::mixed_site()=> Ok(::core::option::Option::None))),
+				);
+				let weak_other = matches!(other_kind, Some(Other::Weak));
+				let strong_is = catch_into_other(
+					quote_spanned!(Span::mixed_site()=> #faible::Faible::as_strong(self)),
+					caught_is.as_ref().filter(|_| weak_other),
+				);
+				let strong_as = catch_into_other(
+					quote_spanned!(Span::mixed_site()=> #faible::Faible::as_strong(self)),
+					caught_as.as_ref().filter(|_| weak_other),
+				);
+				let strong_mut = catch_into_other(
+					quote_spanned!(Span::mixed_site()=> #faible::Faible::as_strong_mut(self)),
+					caught_as.as_ref().filter(|_| weak_other),
+				);
+				let common = quote_spanned!(Span::mixed_site()=> #faible::VariantFilter::common(descriptor, strong, #name));
+				let common_is = catch_into_other(common.clone(), caught_is.as_ref());
+				let common_as = catch_into_other(common, caught_as.as_ref());
+				let common_mut = catch_into_other(
+					quote_spanned!(Span::mixed_site()=> #faible::VariantFilter::common_mut(descriptor, strong, #name)),
+					caught_as.as_ref(),
+				);
+				vec![
+					quote_spanned! {Span::mixed_site()=>
+						#is_signature {
+							let strong = #strong_is;
+							let descriptor = &#root_descriptor;
+							let descriptor = &#descriptor;
+							Ok(match #common_is {
+								::core::option::Option::Some(common) => #probing == #faible::Probing::Off || (#variant_ref.is_ok() && #selected),
+								::core::option::Option::None => false,
+							})
+						}
+					},
+					quote_spanned! {Span::mixed_site()=>
+						#as_ref_signature {
+							let strong = #strong_as;
+							let descriptor = &#root_descriptor;
+							let descriptor = &#descriptor;
+							let common = match #common_as {
+								::core::option::Option::Some(common) => common,
+								::core::option::Option::None => return Ok(::core::option::Option::None),
+							};
+							let variant = match (#probing, #variant_ref) {
+								(#faible::Probing::Off, variant) => variant?,
+								(_, ::core::result::Result::Ok(variant)) if #selected => variant,
+								(_, _) => return Ok(::core::option::Option::None),
+							};
+							#[allow(unreachable_patterns)]
+							match variant {
+								#ref_ty::#ident { #(#members: #bindings),* } => Ok(::core::option::Option::Some((#(#bindings),*))),
+								_ => unreachable!(),
+							}
+						}
+					},
+					quote_spanned! {Span::mixed_site()=>
+						#as_mut_signature {
+							let descriptor = &#root_descriptor;
+							let descriptor = &#descriptor;
+							let strong = #strong_as;
+							if #probing != #faible::Probing::Off && !match #common_as {
+								::core::option::Option::Some(common) => #variant_ref.is_ok() && #selected,
+								::core::option::Option::None => false,
+							} {
+								return Ok(::core::option::Option::None);
+							}
+							let strong = #strong_mut;
+							let mut common = match #common_mut {
+								::core::result::Result::Ok(common) => common,
+								::core::result::Result::Err(_) => return Ok(::core::option::Option::None),
+							};
+							#[allow(unreachable_patterns)]
+							match (#mut_ty::#ident {
+								#(#field_muts,)*
+							}) {
+								#mut_ty::#ident { #(#members: #bindings),* } => Ok(::core::option::Option::Some((#(#bindings),*))),
+								_ => unreachable!(),
+							}
+						}
+					},
+				]
+			}
+		});
+
 		owned_variants.push(Variant {
 			attrs: attrs.clone(),
 			ident: ident.clone(),
@@ -497,6 +909,20 @@ fn process_enum(enum_: ItemEnum, args: &Args, errors: &mut Vec<Error>) -> Proces
 			discriminant: discriminant.clone(),
 		});
 
+		kind_variants.push(Variant {
+			attrs: attrs.clone(),
+			ident: ident.clone(),
+			fields: Fields::Unit,
+			discriminant: discriminant.clone(),
+		});
+		kind_weak_names.push(if other.is_some() {
+			quote_spanned!(Span::mixed_site()=> ::core::option::Option::None)
+		} else {
+			let name = variant_names.last().expect("unreachable");
+			quote_spanned!(Span::mixed_site()=> ::core::option::Option::Some(&#name))
+		});
+		kind_idents.push(ident.clone());
+
 		ref_variants.push(Variant {
 			attrs: attrs.clone(),
 			ident: ident.clone(),
@@ -513,7 +939,10 @@ fn process_enum(enum_: ItemEnum, args: &Args, errors: &mut Vec<Error>) -> Proces
 			discriminant: discriminant.clone(),
 		});
 
-		variant_field_refs.push(field_refs);
+		if other.is_none() {
+			variant_views.push(view);
+			variant_field_refs.push(field_refs);
+		}
 
 		mut_variants.push(Variant {
 			attrs: attrs.clone(),
@@ -531,7 +960,9 @@ fn process_enum(enum_: ItemEnum, args: &Args, errors: &mut Vec<Error>) -> Proces
 			discriminant: discriminant.clone(),
 		});
 
-		variant_field_muts.push(field_muts);
+		if other.is_none() {
+			variant_field_muts.push(field_muts);
+		}
 	}
 
 	let borrow_generics = {
@@ -544,8 +975,174 @@ fn process_enum(enum_: ItemEnum, args: &Args, errors: &mut Vec<Error>) -> Proces
 		generics
 	};
 	let where_ = generics.where_clause.as_ref();
+	let variant_indices = (0..variant_idents.len()).collect::<Vec<_>>();
 
-	let items = vec![
+	// Names are passed to `VariantFilter::classify` as slice, so they must all have the same type.
+	let name_kinds = variant_names.iter().map(literal_kind).collect::<Vec<_>>();
+	let classifiable = classifiable
+		&& matches!(name_kinds.first(), Some(Some(_)))
+		&& name_kinds.windows(2).all(|pair| pair[0] == pair[1]);
+
+	let error = quote_spanned! {Span::mixed_site()=>
+		<<#descriptor_type as #faible::Descriptor>::Error as #faible::Error>
+	};
+
+	let kind_fallback = if let Some((other_ident, ..)) = &other_variant {
+		quote_spanned!(Span::mixed_site()=> Ok(found.unwrap_or(#kind_ty::#other_ident)))
+	} else {
+		quote_spanned!(Span::mixed_site()=> found.ok_or_else(#error::no_variant_recognized))
+	};
+	let ref_fallback = if let Some((other_ident, other, member)) = &other_variant {
+		let member = member.iter().collect::<Vec<_>>();
+		let source_ref = match other {
+			Other::Weak => quote_spanned!(Span::mixed_site()=> &self.0),
+			Other::Strong => quote_spanned!(Span::mixed_site()=> strong),
+		};
+		quote_spanned! {Span::mixed_site()=>
+			Ok(found.unwrap_or_else(|| #ref_ty::#other_ident {
+				#(#member: #faible::View::from_ref(#source_ref),)*
+			}))
+		}
+	} else {
+		quote_spanned! {Span::mixed_site()=>
+			found.ok_or_else(#error::no_variant_recognized)
+		}
+	};
+
+	// `Classification::Unrecognized` skips the sequential checks and goes straight to the other variant, if any.
+	let (kind_unrecognized, ref_unrecognized) = match &other_variant {
+		Some((other_ident, other, member)) => {
+			let member = member.iter().collect::<Vec<_>>();
+			let source_ref = match other {
+				Other::Weak => quote_spanned!(Span::mixed_site()=> &self.0),
+				Other::Strong => quote_spanned!(Span::mixed_site()=> strong),
+			};
+			(
+				quote_spanned!(Span::mixed_site()=> Ok(#kind_ty::#other_ident)),
+				quote_spanned! {Span::mixed_site()=>
+					Ok(#ref_ty::#other_ident {
+						#(#member: #faible::View::from_ref(#source_ref),)*
+					})
+				},
+			)
+		}
+		None => (
+			quote_spanned!(Span::mixed_site()=> Err(#error::no_variant_recognized())),
+			quote_spanned!(Span::mixed_site()=> Err(#error::no_variant_recognized())),
+		),
+	};
+	// With an other variant, failed conversions, classifications and filters select it instead of erroring.
+	let (kind_caught, ref_caught) = match &other_variant {
+		Some(_) => (Some(&kind_unrecognized), Some(&ref_unrecognized)),
+		None => (None, None),
+	};
+	let weak_other = matches!(other_variant, Some((_, Other::Weak, _)));
+	let strong = quote_spanned!(Span::mixed_site()=> #faible::Faible::as_strong(self));
+	let kind_strong = catch_into_other(strong.clone(), kind_caught.filter(|_| weak_other));
+	let ref_strong = catch_into_other(strong, ref_caught.filter(|_| weak_other));
+	let common = variant_names
+		.iter()
+		.map(|name| quote_spanned!(Span::mixed_site()=> #faible::VariantFilter::common(descriptor, strong, #name)))
+		.collect::<Vec<_>>();
+	let kind_commons = common
+		.iter()
+		.map(|common| catch_into_other(common.clone(), kind_caught))
+		.collect::<Vec<_>>();
+	let ref_commons = common
+		.into_iter()
+		.map(|common| catch_into_other(common, ref_caught))
+		.collect::<Vec<_>>();
+	let (ref_classification, kind_classification) = if classifiable {
+		let classify = quote_spanned! {Span::mixed_site()=>
+			<_ as #faible::VariantFilter<'_, _, <#descriptor_type as #faible::Descriptor>::Error, _>>::classify(
+				descriptor,
+				strong,
+				&[#(#variant_names),*],
+			)
+		};
+		let kind_classify = catch_into_other(classify.clone(), kind_caught);
+		let ref_classify = catch_into_other(classify, ref_caught);
+		(
+			quote_spanned! {Span::mixed_site()=>
+				match #ref_classify {
+					#(#faible::Classification::Variant(#variant_indices) => {
+						let descriptor = &#variant_descriptors;
+						if let ::core::option::Option::Some(common) = #ref_commons {
+							return Ok(#ref_ty::#variant_idents {
+								#(#variant_field_refs,)*
+							});
+						}
+					})*
+					#faible::Classification::Unrecognized => return #ref_unrecognized,
+					_ => {}
+				}
+			},
+			quote_spanned! {Span::mixed_site()=>
+				match #kind_classify {
+					#(#faible::Classification::Variant(#variant_indices) => {
+						let descriptor = &#variant_descriptors;
+						if #kind_commons.is_some() {
+							return Ok(#kind_ty::#variant_idents);
+						}
+					})*
+					#faible::Classification::Unrecognized => return #kind_unrecognized,
+					_ => {}
+				}
+			},
+		)
+	} else {
+		(
+			quote_spanned!(Span::mixed_site()=>),
+			quote_spanned!(Span::mixed_site()=>),
+		)
+	};
+	// `as_variant_mut` recognises the variant through `variant` first, with shared borrows only,
+	// and then takes a single exclusive borrow for just that variant.
+	let mut_arms = variant_indices.iter().map(|&index| {
+		let descriptor = &variant_descriptors[index];
+		let name = &variant_names[index];
+		let ident = &variant_idents[index];
+		let field_muts = &variant_field_muts[index];
+		if variant_views[index] {
+			quote_spanned! {Span::mixed_site()=>
+				#kind_ty::#ident => Ok(#mut_ty::#ident {
+					#(#field_muts,)*
+				}),
+			}
+		} else {
+			quote_spanned! {Span::mixed_site()=>
+				#kind_ty::#ident => {
+					let strong = #faible::Faible::as_strong_mut(self)?;
+					let descriptor = &#descriptor;
+					match #faible::VariantFilter::common_mut(descriptor, strong, #name)? {
+						::core::result::Result::Ok(mut common) => Ok(#mut_ty::#ident {
+							#(#field_muts,)*
+						}),
+						// `common` recognised this variant, so this only happens with inconsistent filters.
+						::core::result::Result::Err(_) => Err(#error::no_variant_recognized()),
+					}
+				}
+			}
+		}
+	});
+	let other_mut_arm = other_variant.as_ref().map(|(other_ident, other, member)| {
+		let member = member.iter().collect::<Vec<_>>();
+		match other {
+			Other::Weak => quote_spanned! {Span::mixed_site()=>
+				#kind_ty::#other_ident => Ok(#mut_ty::#other_ident {
+					#(#member: #faible::View::from_mut(&mut self.0),)*
+				}),
+			},
+			Other::Strong => quote_spanned! {Span::mixed_site()=>
+				#kind_ty::#other_ident => Ok(#mut_ty::#other_ident {
+					#(#member: #faible::View::from_mut(#faible::Faible::as_strong_mut(self)?),)*
+				}),
+			},
+		}
+	});
+	// The kind enum derives its own traits, so the original ones would clash.
+	let kind_attrs = attrs.iter().filter(|attr| !attr.path.is_ident("derive"));
+	let mut items = vec![
 		quote_spanned! {Span::mixed_site()=>
 			#(#attrs)*
 			#[automatically_derived]
@@ -567,54 +1164,163 @@ fn process_enum(enum_: ItemEnum, args: &Args, errors: &mut Vec<Error>) -> Proces
 				#(#mut_variants,)*
 			}
 		},
+		quote_spanned! {Span::mixed_site()=>
+			#(#kind_attrs)*
+			#[automatically_derived]
+			#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+			#vis #enum_token #kind_ty {
+				#(#kind_variants,)*
+			}
+		},
 	];
+	// Only literals and constants are promoted to `'static` references, so other names aren't exposed.
+	if variant_names.iter().all(static_name) {
+		items.push(quote_spanned! {Span::mixed_site()=>
+			#[automatically_derived]
+			impl #kind_ty {
+				/// Returns the weak name of this variant, or [`None`] for an `#[faible(other)]` variant.
+				#[must_use]
+				#vis fn weak_name(self) -> ::core::option::Option<&'static dyn ::core::fmt::Debug> {
+					match self {
+						#(Self::#kind_idents => #kind_weak_names,)*
+					}
+				}
+			}
+		});
+	}
 
-	let methods = vec![
+	let mut methods = vec![
 		quote_spanned! {Span::mixed_site()=>
-			#vis fn as_variant<'access>(&'access self) -> ::core::result::Result<
-				#ref_ty #borrow_generics,
+			#vis fn variant(&self) -> ::core::result::Result<
+				#kind_ty,
 				<#descriptor_type as #faible::Descriptor>::Error,
 			> {
-				let strong = #faible::Faible::as_strong(self)?;
+				let strong = #kind_strong;
 				let descriptor = &#descriptor;
 
+				#kind_classification
+
+				let mut found = ::core::option::Option::None;
 				#({
 					let descriptor = &#variant_descriptors;
-					if let ::core::option::Option::Some(common) = #faible::VariantFilter::common(descriptor, strong, #variant_names)? {
-						return Ok(#ref_ty::#variant_idents {
-							#(#variant_field_refs,)*
-						});
+					if let ::core::option::Option::Some(common) = #kind_commons {
+						let probing = <_ as #faible::VariantFilter<'_, _, <#descriptor_type as #faible::Descriptor>::Error, _>>::probing(descriptor, strong, #variant_names);
+						// Fields are only resolved for probing filters, which need them to tell variants apart.
+						if probing == #faible::Probing::Off || (|| -> ::core::result::Result<_, <#descriptor_type as #faible::Descriptor>::Error> {
+							Ok(#ref_ty::#variant_idents {
+								#(#variant_field_refs,)*
+							})
+						})().is_ok() {
+							if probing != #faible::Probing::Strict {
+								return Ok(#kind_ty::#variant_idents);
+							}
+							if found.is_some() {
+								return Err(#error::ambiguous_variant());
+							}
+							found = ::core::option::Option::Some(#kind_ty::#variant_idents);
+						}
 					}
 				})*
-				{
-					Err(<<#descriptor_type as #faible::Descriptor>::Error as #faible::Error>::no_variant_recognized())
-				}
+				#kind_fallback
 			}
 		},
 		quote_spanned! {Span::mixed_site()=>
-			#vis fn as_variant_mut<'access>(&'access mut self) -> ::core::result::Result<
-				#mut_ty #borrow_generics,
+			#vis fn as_variant<'access>(&'access self) -> ::core::result::Result<
+				#ref_ty #borrow_generics,
 				<#descriptor_type as #faible::Descriptor>::Error,
 			> {
-				let strong = #faible::Faible::as_strong_mut(self)?;
+				let strong = #ref_strong;
 				let descriptor = &#descriptor;
 
+				#ref_classification
+
+				let mut found = ::core::option::Option::None;
 				#({
 					let descriptor = &#variant_descriptors;
-					if #faible::VariantFilter::check_mut(descriptor, strong, #variant_names)? {
-						let mut common = #faible::VariantFilter::common_mut(descriptor, strong, #variant_names)?;
-						return Ok(#mut_ty::#variant_idents {
-							#(#variant_field_muts,)*
-						});
+					if let ::core::option::Option::Some(common) = #ref_commons {
+						let variant = (|| -> ::core::result::Result<_, <#descriptor_type as #faible::Descriptor>::Error> {
+							Ok(#ref_ty::#variant_idents {
+								#(#variant_field_refs,)*
+							})
+						})();
+						match <_ as #faible::VariantFilter<'_, _, <#descriptor_type as #faible::Descriptor>::Error, _>>::probing(descriptor, strong, #variant_names) {
+							#faible::Probing::Off => return variant,
+							#faible::Probing::First => if variant.is_ok() {
+								return variant;
+							},
+							#faible::Probing::Strict => if let ::core::result::Result::Ok(variant) = variant {
+								if found.is_some() {
+									return Err(#error::ambiguous_variant());
+								}
+								found = ::core::option::Option::Some(variant);
+							},
+						}
 					}
 				})*
-				{
-					Err(<<#descriptor_type as #faible::Descriptor>::Error as #faible::Error>::no_variant_recognized())
+				#ref_fallback
+			}
+		},
+		quote_spanned! {Span::mixed_site()=>
+			#vis fn as_variant_mut<'access>(&'access mut self) -> ::core::result::Result<
+				#mut_ty #borrow_generics,
+				<#descriptor_type as #faible::Descriptor>::Error,
+			> {
+				let descriptor = &#descriptor;
+				match self.variant()? {
+					#(#mut_arms)*
+					#other_mut_arm
 				}
 			}
 		},
 	];
 
+	// C-like enums convert to and from their plain Rust counterpart.
+	// Writing variants back needs `UnitVariantAccess`, which not every descriptor implements, so that's opt-in.
+	let (_, type_generics, _) = generics.split_for_impl();
+	if !has_fields {
+		methods.push(quote_spanned! {Span::mixed_site()=>
+			#vis fn to_rust_enum(&self) -> ::core::result::Result<
+				#owned_ty #type_generics,
+				<#descriptor_type as #faible::Descriptor>::Error,
+			> {
+				match self.variant()? {
+					#(#kind_ty::#kind_idents => Ok(#owned_ty::#kind_idents),)*
+				}
+			}
+		});
+	}
+	if *set_variant && has_fields {
+		errors.push(Error::new(
+			ident.span(),
+			"`set_variant` is only available on enums without fields.",
+		));
+	} else if *set_variant {
+		let other_arm = other_variant.as_ref().map(|(other_ident, ..)| {
+			quote_spanned! {Span::mixed_site()=>
+				#owned_ty::#other_ident => Err(#error::no_variant_recognized()),
+			}
+		});
+		methods.push(quote_spanned! {Span::mixed_site()=>
+			#vis fn set_variant(&mut self, variant: #owned_ty #type_generics) -> ::core::result::Result<
+				(),
+				<#descriptor_type as #faible::Descriptor>::Error,
+			> {
+				match variant {
+					#(#owned_ty::#variant_idents => {
+						let descriptor = &#descriptor;
+						let strong = #faible::Faible::as_strong_mut(self)?;
+						let descriptor = &#variant_descriptors;
+						#faible::UnitVariantAccess::set(descriptor, strong, #variant_names)
+					})*
+					#other_arm
+				}
+			}
+		});
+	}
+
+	methods.extend(variant_accessors);
+	methods.extend(common_accessors);
+
 	Processed {
 		attrs,
 		vis,
@@ -632,6 +1338,18 @@ struct InnerArgs {
 	descriptor: Expr,
 	name: Option<Expr>,
 	names: Option<Expr>,
+	by_value: bool,
+	other: Option<Other>,
+	view: bool,
+	flatten: bool,
+	aliases: Vec<Expr>,
+}
+
+/// Which value an `#[faible(other)]` variant exposes.
+#[derive(Clone, Copy, Debug)]
+enum Other {
+	Weak,
+	Strong,
 }
 impl Debug for InnerArgs {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -646,6 +1364,11 @@ impl Default for InnerArgs {
 			descriptor: parse_quote_spanned!(Span::mixed_site()=> *descriptor),
 			name: None,
 			names: None,
+			by_value: false,
+			other: None,
+			view: false,
+			flatten: false,
+			aliases: vec![],
 		}
 	}
 }
@@ -662,6 +1385,35 @@ fn take_args_from_attrs(attrs: &mut Vec<Attribute>, errors: &mut Vec<Error>) ->
 
 			if input.peek(Token![_]) {
 				input.parse::<Token![_]>().expect("unreachable");
+			} else if input.peek(kw::by_value) && {
+				let fork = input.fork();
+				fork.parse::<kw::by_value>().expect("unreachable");
+				fork.is_empty() || fork.peek(Token![,])
+			} {
+				input.parse::<kw::by_value>().expect("unreachable");
+				inner_args.by_value = true;
+			} else if input.peek(kw::view) && {
+				let fork = input.fork();
+				fork.parse::<kw::view>().expect("unreachable");
+				fork.is_empty() || fork.peek(Token![,])
+			} {
+				input.parse::<kw::view>().expect("unreachable");
+				inner_args.view = true;
+			} else if input.peek(kw::flatten) && {
+				let fork = input.fork();
+				fork.parse::<kw::flatten>().expect("unreachable");
+				fork.is_empty() || fork.peek(Token![,])
+			} {
+				input.parse::<kw::flatten>().expect("unreachable");
+				inner_args.flatten = true;
+			} else if input.peek(kw::other) && {
+				let fork = input.fork();
+				fork.parse::<kw::other>().expect("unreachable");
+				fork.is_empty() || fork.peek(Token![,]) || fork.peek(Token![=])
+			} {
+				parse_other(&input, &mut inner_args, errors);
+			} else if input.peek(kw::alias) && input.peek2(Token![=]) {
+				parse_alias(&input, &mut inner_args, errors);
 			} else {
 				input.insist(errors).then_set(&mut inner_args.descriptor);
 			}
@@ -691,6 +1443,19 @@ fn take_args_from_attrs(attrs: &mut Vec<Attribute>, errors: &mut Vec<Error>) ->
 						.insist(errors)
 						.map(Some)
 						.then_set(&mut inner_args.names);
+				} else if lookahead.peek(kw::by_value) {
+					input.parse::<kw::by_value>().expect("unreachable");
+					inner_args.by_value = true;
+				} else if lookahead.peek(kw::view) {
+					input.parse::<kw::view>().expect("unreachable");
+					inner_args.view = true;
+				} else if lookahead.peek(kw::flatten) {
+					input.parse::<kw::flatten>().expect("unreachable");
+					inner_args.flatten = true;
+				} else if lookahead.peek(kw::other) {
+					parse_other(&input, &mut inner_args, errors);
+				} else if lookahead.peek(kw::alias) {
+					parse_alias(&input, &mut inner_args, errors);
 				} else {
 					errors.push(lookahead.error())
 				}
@@ -708,6 +1473,42 @@ fn take_args_from_attrs(attrs: &mut Vec<Attribute>, errors: &mut Vec<Error>) ->
 	inner_args
 }
 
+fn parse_alias(input: ParseStream, inner_args: &mut InnerArgs, errors: &mut Vec<Error>) {
+	input.parse::<kw::alias>().expect("unreachable");
+	input.insist::<Token![=]>(errors);
+	if let Some(alias) = input.insist(errors) {
+		inner_args.aliases.push(alias);
+	}
+}
+
+fn parse_other(input: ParseStream, inner_args: &mut InnerArgs, errors: &mut Vec<Error>) {
+	let other = input.parse::<kw::other>().expect("unreachable");
+	if inner_args.other.is_some() {
+		errors.push(Error::new(other.span, "Duplicate other definition."))
+	}
+	inner_args.other = Some(
+		if input
+			.parse::<Option<Token![=]>>()
+			.expect("infallible")
+			.is_some()
+		{
+			let lookahead = input.lookahead1();
+			if lookahead.peek(kw::weak) {
+				input.parse::<kw::weak>().expect("unreachable");
+				Other::Weak
+			} else if lookahead.peek(kw::strong) {
+				input.parse::<kw::strong>().expect("unreachable");
+				Other::Strong
+			} else {
+				errors.push(lookahead.error());
+				Other::Strong
+			}
+		} else {
+			Other::Strong
+		},
+	);
+}
+
 fn process_struct(struct_: ItemStruct, args: &Args, errors: &mut Vec<Error>) -> Processed {
 	let Args {
 		descriptor,
@@ -715,6 +1516,12 @@ fn process_struct(struct_: ItemStruct, args: &Args, errors: &mut Vec<Error>) ->
 		names,
 		nested_names: _, //TODO: Ensure unset.
 		no_weak_conversions: _,
+		set_variant: _, //TODO: Ensure unset.
+		weak: _,
+		strong: _,
+		version_field,
+		version,
+		migrate,
 	} = args;
 	let ItemStruct {
 		attrs,
@@ -727,22 +1534,129 @@ fn process_struct(struct_: ItemStruct, args: &Args, errors: &mut Vec<Error>) ->
 	} = struct_;
 
 	let descriptor_type = descriptor_type(descriptor, errors);
+	let error = quote_spanned! {Span::mixed_site()=>
+		<<#descriptor_type as #faible::Descriptor>::Error as #faible::Error>
+	};
+
+	// Shared accessors require the current version, while exclusive ones upgrade to it first.
+	let (version_check, version_upgrade, upgrade) = match (version_field, version, migrate) {
+		(None, None, None) => (
+			quote_spanned!(Span::mixed_site()=>),
+			quote_spanned!(Span::mixed_site()=>),
+			None,
+		),
+		(Some(version_field), Some(version), Some(migrate)) => (
+			quote_spanned! {Span::mixed_site()=>
+				let version = #faible::VersionAccess::version(descriptor, strong, #version_field)?.unwrap_or(0);
+				if version < #version {
+					return Err(#error::outdated_version(&version));
+				}
+				if version > #version {
+					return Err(#error::unknown_version(&version));
+				}
+			},
+			quote_spanned!(Span::mixed_site()=> self.upgrade()?;),
+			Some(quote_spanned! {Span::mixed_site()=>
+				/// Migrates the wrapped value to the current schema version, if it isn't already.
+				#vis fn upgrade(&mut self) -> ::core::result::Result<(), <#descriptor_type as #faible::Descriptor>::Error> {
+					let descriptor = &#descriptor;
+					let strong = #faible::Descriptor::strong(descriptor, &self.0)?;
+					let current: u64 = #version;
+					let version = #faible::VersionAccess::version(descriptor, strong, #version_field)?.unwrap_or(0);
+					if version == current {
+						return Ok(());
+					}
+					if version > current {
+						return Err(#error::unknown_version(&version));
+					}
+					// Each step is recorded right away, so that a failing one isn't preceded by repeated ones on retry.
+					for version in version..current {
+						#migrate(version, &mut self.0)?;
+						let strong = #faible::Descriptor::strong_mut(descriptor, &mut self.0)?;
+						#faible::VersionAccess::set_version(descriptor, strong, #version_field, version + 1)?;
+					}
+					Ok(())
+				}
+			}),
+		),
+		_ => {
+			errors.push(Error::new(
+				ident.span(),
+				"`version_field`, `version` and `migrate` must be used together.",
+			));
+			(
+				quote_spanned!(Span::mixed_site()=>),
+				quote_spanned!(Span::mixed_site()=>),
+				None,
+			)
+		}
+	};
 
 	let fields_span = fields.span();
-	let methods = fields
+	let mut methods = field_accessors(
+		fields,
+		descriptor,
+		faible,
+		names,
+		&version_check,
+		&version_upgrade,
+		errors,
+	);
+	methods.extend(upgrade);
+
+	Processed {
+		attrs,
+		vis,
+		struct_token,
+		ident,
+		generics,
+		fields_span,
+		methods,
+		semicolon: semi_token.unwrap_or_else(|| Token![;](fields_span)),
+		items: vec![],
+	}
+}
+
+/// Generates accessors for `fields` of the strong value, as on `#[faible]` structs.
+///
+/// `version_check` is spliced into shared accessors after `strong` is available,
+/// and `version_upgrade` at the start of exclusive ones.
+fn field_accessors(
+	fields: Fields,
+	descriptor: &Expr,
+	faible: &Path,
+	names: &Expr,
+	version_check: &TokenStream,
+	version_upgrade: &TokenStream,
+	errors: &mut Vec<Error>,
+) -> Vec<TokenStream> {
+	let descriptor_type = descriptor_type(descriptor, errors);
+
+	fields
 		.into_iter()
 		.enumerate()
 		.map(
 			|(
 				index,
 				Field {
-					attrs,
+					mut attrs,
 					vis,
 					ident,
 					colon_token: _,
 					ty,
 				},
 			)| {
+				let InnerArgs {
+					descriptor: field_descriptor,
+					name,
+					names: _, //TODO: Ensure empty.
+					by_value,
+					other: _, //TODO: Ensure unset.
+					view: _,  //TODO: Ensure unset.
+					flatten,
+					aliases,
+				} = take_args_from_attrs(&mut attrs, errors);
+
 				let ident = ident.unwrap_or_else(|| Ident::new(Buffer::new().format(index), ty.span()));
 				let ident_string = ident.to_string();
 
@@ -755,52 +1669,135 @@ fn process_struct(struct_: ItemStruct, args: &Args, errors: &mut Vec<Error>) ->
 				let set = Ident::new(&format!("set_{ident_string}"), ident.span());
 				let insert = Ident::new(&format!("insert_{ident_string}"), ident.span());
 
-				let name = make_name("field",false,ident.span(), Some(&ident),index, None,names, errors);
+				let name = make_name("field", false, ident.span(), Some(&ident), index, None, name.as_ref().unwrap_or(names), errors);
+				let aliases = aliases
+					.iter()
+					.map(|alias| make_name("alias", false, ident.span(), Some(&ident), index, None, alias, errors))
+					.collect::<Vec<_>>();
+
+				// Getters fall back to the first present alias iff the field itself is absent.
+				let (read_name, remove_aliases) = if aliases.is_empty() {
+					(quote_spanned!(Span::mixed_site()=> #name), None)
+				} else {
+					let remove_aliases = Ident::new(&format!("remove_{ident_string}_aliases"), ident.span());
+					(
+						quote_spanned! {Span::mixed_site()=>
+							if #faible::FieldPresence::contains(descriptor, &*strong, #name)? {
+								#name
+							} #(else if #faible::FieldPresence::contains(descriptor, &*strong, #aliases)? {
+								#aliases
+							})* else {
+								#name
+							}
+						},
+						Some(quote_spanned! {ty.span().resolved_at(Span::mixed_site())=>
+							#(#attrs)*
+							#vis fn #remove_aliases(&mut self) -> ::core::result::Result<(), <#descriptor_type as #faible::Descriptor>::Error> {
+								#version_upgrade
+								let descriptor = &#descriptor;
+								let strong = #faible::Descriptor::strong_mut(descriptor, &mut self.0)?;
+								#(#faible::FieldPresence::remove(descriptor, strong, #aliases)?;)*
+								Ok(())
+							}
+						}),
+					)
+				};
+
+				// Flattened fields view the whole weak value, so they can't fail unless versioned.
+				// Other accessors return `Result`, which is `#[must_use]` already.
+				if flatten && !version_check.is_empty() {
+					return quote_spanned! {ty.span().resolved_at(Span::mixed_site())=>
+						#(#attrs)*
+						#vis fn #get(&self) -> ::core::result::Result<&#ty, <#descriptor_type as #faible::Descriptor>::Error> {
+							let descriptor = &#descriptor;
+							let strong = #faible::Descriptor::strong(descriptor, &self.0)?;
+							#version_check
+							Ok(#faible::View::from_ref(&self.0))
+						}
+
+						#(#attrs)*
+						#vis fn #get_mut(&mut self) -> ::core::result::Result<&mut #ty, <#descriptor_type as #faible::Descriptor>::Error> {
+							#version_upgrade
+							Ok(#faible::View::from_mut(&mut self.0))
+						}
+					};
+				}
+				if flatten {
+					return quote_spanned! {ty.span().resolved_at(Span::mixed_site())=>
+						#(#attrs)*
+						#[must_use]
+						#vis fn #get(&self) -> &#ty {
+							#faible::View::from_ref(&self.0)
+						}
+
+						#(#attrs)*
+						#[must_use]
+						#vis fn #get_mut(&mut self) -> &mut #ty {
+							#faible::View::from_mut(&mut self.0)
+						}
+					};
+				}
+
+				if by_value {
+					return quote_spanned! {ty.span().resolved_at(Span::mixed_site())=>
+						#(#attrs)*
+						#vis fn #get(&self) -> ::core::result::Result<#ty, <#descriptor_type as #faible::Descriptor>::Error> {
+							let descriptor = &#descriptor;
+							let strong = #faible::Descriptor::strong(descriptor, &self.0)?;
+							#version_check
+							#faible::FieldValueAccess::get(&#field_descriptor, strong, #read_name)
+						}
+
+						#(#attrs)*
+						#vis fn #set(&mut self, value: #ty) -> ::core::result::Result<(), <#descriptor_type as #faible::Descriptor>::Error> {
+							#version_upgrade
+							let descriptor = &#descriptor;
+							let strong = #faible::Descriptor::strong_mut(descriptor, &mut self.0)?;
+							#faible::FieldValueAccess::set(&#field_descriptor, strong, #name, value)
+						}
+
+						#remove_aliases
+					};
+				}
 
 				quote_spanned! {ty.span().resolved_at(Span::mixed_site())=>
 					#(#attrs)*
 					#vis fn #get(&self) -> ::core::result::Result<&#ty, <#descriptor_type as #faible::Descriptor>::Error> {
-						let descriptor = #descriptor;
-						let strong = #faible::Descriptor::strong(&descriptor, &self.0)?;
-						#faible::FieldAccess::get(&descriptor, strong, #name)
+						let descriptor = &#descriptor;
+						let strong = #faible::Descriptor::strong(descriptor, &self.0)?;
+						#version_check
+						#faible::FieldAccess::get(&#field_descriptor, strong, #read_name)
 					}
 
 					#(#attrs)*
 					#vis fn #get_mut(&mut self) -> ::core::result::Result<&mut #ty, <#descriptor_type as #faible::Descriptor>::Error> {
-						let descriptor = #descriptor;
-						let strong = #faible::Descriptor::strong_mut(&descriptor, &mut self.0)?;
-						#faible::FieldAccess::get_mut(&descriptor, strong, #name)
+						#version_upgrade
+						let descriptor = &#descriptor;
+						let strong = #faible::Descriptor::strong_mut(descriptor, &mut self.0)?;
+						#faible::FieldAccess::get_mut(&#field_descriptor, strong, #read_name)
 					}
 
 					#(#attrs)*
 					#vis fn #set(&mut self, value: #ty) -> ::core::result::Result<(), <#descriptor_type as #faible::Descriptor>::Error> {
-						let descriptor = #descriptor;
-						let strong = #faible::Descriptor::strong_mut(&descriptor, &mut self.0)?;
-						#faible::FieldAccess::set(&descriptor, strong, #name, value)
+						#version_upgrade
+						let descriptor = &#descriptor;
+						let strong = #faible::Descriptor::strong_mut(descriptor, &mut self.0)?;
+						#faible::FieldAccess::set(&#field_descriptor, strong, #name, value)
 					}
 
 					#(#attrs)*
 					#vis fn #insert(&mut self, value: #ty) -> ::core::result::Result<(&mut #ty, ::core::option::Option<#ty>), <#descriptor_type as #faible::Descriptor>::Error> {
-						let descriptor = #descriptor;
-						let strong = #faible::Descriptor::strong_mut(&descriptor, &mut self.0)?;
-						#faible::FieldAccess::insert(&descriptor, strong, #name, value)
+						#version_upgrade
+						let descriptor = &#descriptor;
+						let strong = #faible::Descriptor::strong_mut(descriptor, &mut self.0)?;
+						#faible::FieldAccess::insert(&#field_descriptor, strong, #name, value)
 					}
+
+					#remove_aliases
 				}
 			},
 		)
-		.collect();
-
-	Processed {
-		attrs,
-		vis,
-		struct_token,
-		ident,
-		generics,
-		fields_span,
-		methods,
-		semicolon: semi_token.unwrap_or_else(|| Token![;](fields_span)),
-		items: vec![],
-	}
+		.collect()
 }
 
 fn process_union(union: ItemUnion, args: &Args, errors: &mut Vec<Error>) -> Processed {
@@ -810,7 +1807,14 @@ fn process_union(union: ItemUnion, args: &Args, errors: &mut Vec<Error>) -> Proc
 		names,
 		nested_names: _, //TODO: Ensure unset.
 		no_weak_conversions: _,
+		set_variant: _, //TODO: Ensure unset.
+		weak: _,
+		strong: _,
+		version_field,
+		version,
+		migrate,
 	} = args;
+	reject_versioning([version_field, version, migrate], errors);
 	let ItemUnion {
 		attrs,
 		vis,
@@ -822,7 +1826,15 @@ fn process_union(union: ItemUnion, args: &Args, errors: &mut Vec<Error>) -> Proc
 
 	let descriptor_type = descriptor_type(descriptor, errors);
 
-	let methods = fields
+	let field_ty = Ident::new(&(ident.to_string() + "Field"), Span::call_site());
+	let ref_ty = Ident::new(&(ident.to_string() + "UnionRef"), Span::call_site());
+
+	let mut field_variants = vec![];
+	let mut ref_variants = vec![];
+	let mut getters = vec![];
+
+	let fields_span = fields.brace_token.span;
+	let mut methods = fields
 		.named
 		.into_iter()
 		.enumerate()
@@ -851,6 +1863,23 @@ fn process_union(union: ItemUnion, args: &Args, errors: &mut Vec<Error>) -> Proc
 
 				let name = make_name("field", false, ident.span(), Some(&ident), index, None, names, errors);
 
+				let variant = Ident::new(
+					&ident_string
+						.strip_prefix("r#")
+						.unwrap_or(&ident_string)
+						.to_upper_camel_case(),
+					ident.span(),
+				);
+				field_variants.push(quote_spanned! {ident.span()=>
+					#(#attrs)*
+					#variant
+				});
+				ref_variants.push(quote_spanned! {ty.span().resolved_at(Span::mixed_site())=>
+					#(#attrs)*
+					#variant(&'access #ty)
+				});
+				getters.push((variant, get.clone()));
+
 				quote_spanned! {ty.span().resolved_at(Span::mixed_site())=>
 					#(#attrs)*
 					#vis fn #get(&self) -> ::core::result::Result<Option<&#ty>, <#descriptor_type as #faible::Descriptor>::Error> {
@@ -882,7 +1911,59 @@ fn process_union(union: ItemUnion, args: &Args, errors: &mut Vec<Error>) -> Proc
 				}
 			},
 		)
-		.collect();
+		.collect::<Vec<_>>();
+
+	let borrow_generics = {
+		let mut generics = generics.clone();
+		generics
+			.params
+			.insert(0, parse_quote_spanned!(Span::mixed_site()=> 'access));
+		generics
+	};
+	let where_ = generics.where_clause.as_ref();
+	let (_, borrow_type_generics, _) = borrow_generics.split_for_impl();
+	let (variants, gets): (Vec<_>, Vec<_>) = getters.into_iter().unzip();
+
+	methods.push(quote_spanned! {Span::mixed_site()=>
+		/// Names the first accessible field, if any.
+		#vis fn which(&self) -> ::core::result::Result<
+			::core::option::Option<#field_ty>,
+			<#descriptor_type as #faible::Descriptor>::Error,
+		> {
+			#(if self.#gets()?.is_some() {
+				return Ok(::core::option::Option::Some(#field_ty::#variants));
+			})*
+			Ok(::core::option::Option::None)
+		}
+
+		/// Borrows the first accessible field, if any.
+		#[allow(clippy::should_implement_trait)]
+		#vis fn as_ref<'access>(&'access self) -> ::core::result::Result<
+			::core::option::Option<#ref_ty #borrow_type_generics>,
+			<#descriptor_type as #faible::Descriptor>::Error,
+		> {
+			#(if let ::core::option::Option::Some(field) = self.#gets()? {
+				return Ok(::core::option::Option::Some(#ref_ty::#variants(field)));
+			})*
+			Ok(::core::option::Option::None)
+		}
+	});
+
+	let items = vec![
+		quote_spanned! {Span::mixed_site()=>
+			#[automatically_derived]
+			#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+			#vis enum #field_ty {
+				#(#field_variants,)*
+			}
+		},
+		quote_spanned! {Span::mixed_site()=>
+			#[automatically_derived]
+			#vis enum #ref_ty #borrow_generics #where_ {
+				#(#ref_variants,)*
+			}
+		},
+	];
 
 	Processed {
 		attrs,
@@ -890,10 +1971,75 @@ fn process_union(union: ItemUnion, args: &Args, errors: &mut Vec<Error>) -> Proc
 		struct_token: Token![struct](union_token.span),
 		ident,
 		generics,
-		fields_span: fields.brace_token.span,
+		fields_span,
 		methods,
-		semicolon: Token![;](fields.brace_token.span),
-		items: vec![],
+		semicolon: Token![;](fields_span),
+		items,
+	}
+}
+
+/// Reports `version_field`, `version` and `migrate` on items other than structs, which ignore them.
+fn reject_versioning(args: [&Option<Expr>; 3], errors: &mut Vec<Error>) {
+	for arg in args.iter().copied().flatten() {
+		errors.push(Error::new_spanned(
+			arg,
+			"`version_field`, `version` and `migrate` are only supported on structs.",
+		));
+	}
+}
+
+/// Reports `#[faible(alias = …)]` outside of struct and common fields, where it would be ignored.
+fn reject_aliases(aliases: &[Expr], errors: &mut Vec<Error>) {
+	for alias in aliases {
+		errors.push(Error::new(
+			alias.span(),
+			"Aliases are only supported on struct and common fields.",
+		));
+	}
+}
+
+/// Applies `?` to `result`, or returns `caught` on error where an other variant catches it.
+fn catch_into_other(result: TokenStream, caught: Option<&TokenStream>) -> TokenStream {
+	match caught {
+		Some(caught) => quote_spanned! {Span::mixed_site()=>
+			match #result {
+				::core::result::Result::Ok(value) => value,
+				::core::result::Result::Err(_) => return #caught,
+			}
+		},
+		None => quote_spanned!(Span::mixed_site()=> #result?),
+	}
+}
+
+/// Checks whether `name` is a literal or constant path, which `weak_name()` can return as `'static`.
+fn static_name(name: &Expr) -> bool {
+	match name {
+		Expr::Group(ExprGroup { expr, .. }) | Expr::Paren(ExprParen { expr, .. }) => {
+			static_name(expr)
+		}
+		Expr::Path(_) => true,
+		name => literal_kind(name).is_some(),
+	}
+}
+
+/// Identifies the type of literal names, like `"str"` or `"int_i8"`, or [`None`] for other expressions.
+fn literal_kind(name: &Expr) -> Option<String> {
+	match name {
+		Expr::Group(ExprGroup { expr, .. }) | Expr::Paren(ExprParen { expr, .. }) => {
+			literal_kind(expr)
+		}
+		Expr::Lit(ExprLit {
+			lit: Lit::Str(_), ..
+		}) => Some("str".to_string()),
+		Expr::Lit(ExprLit {
+			lit: Lit::Int(int), ..
+		}) => Some(format!("int_{}", int.suffix())),
+		Expr::Unary(ExprUnary {
+			op: UnOp::Neg(_),
+			expr,
+			..
+		}) => literal_kind(expr).filter(|kind| kind.starts_with("int_")),
+		_ => None,
 	}
 }
 
@@ -910,7 +2056,8 @@ fn make_name(
 ) -> Expr {
 	struct NameVisitor<'a> {
 		name_kind: &'a str,
-		nested: bool, //TODO: Adjust error messages.
+		#[allow(dead_code)] //TODO: Adjust error messages.
+		nested: bool,
 		span: Span,
 		ident: Option<&'a Ident>,
 		index: usize,
@@ -1003,7 +2150,7 @@ Replaced literals are: "kebab_case", "lowerCamelCase", "PascalCase", "SHOUTY_KEB
 			*i = Ident::new(
 				&match i.to_string().as_str() {
 					"__faible__name_required" => {
-						self.errors.push(Error::new(self.span, &format!("A {} name expression is required. (`#[faible(…, names = <expr>)]`, try identifiers and string literals for more information.)", self.name_kind)));
+						self.errors.push(Error::new(self.span, format!("A {} name expression is required. (`#[faible(…, names = <expr>)]`, try identifiers and string literals for more information.)", self.name_kind)));
 						*i = Ident::new(
 							"__faible__name_required",
 							self.span.resolved_at(Span::mixed_site()),
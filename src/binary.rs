@@ -0,0 +1,368 @@
+//! Descriptors over byte buffers, for zero-copy binary formats like file or packet headers.
+//!
+//! Fields are named by their byte offset and typed as [`Plain`] values,
+//! which includes bytes, byte arrays and the endian-aware integers in this module:
+//!
+//! ```
+//! use faible::{binary::{self, U16Le, U32Be}, faible};
+//! # pub struct Error;
+//! # impl faible::Error for Error {
+//! #     fn no_variant_recognized() -> Self { Self }
+//! # }
+//!
+//! #[faible(
+//!     binary::Layout::<Error, 12>::new(),
+//!     weak = [u8; 12],
+//!     strong = [u8; 12],
+//!     names = index,
+//!     no_weak_conversions,
+//! )]
+//! pub struct Header {
+//!     #[faible(_, name = 0x0)]
+//!     pub magic: [u8; 4],
+//!     #[faible(_, name = 0x4)]
+//!     pub version: U16Le,
+//!     #[faible(_, name = 0x8)]
+//!     pub length: U32Be,
+//! }
+//!
+//! let mut header = Header::from(*b"FAIB\x02\0\0\0\0\0\x01\0");
+//! assert_eq!(header.version().ok().map(|version| version.get()), Some(2));
+//!
+//! header.set_length(U32Be::new(0x20)).ok();
+//! assert_eq!(&header.0[8..], &[0, 0, 0, 0x20]);
+//! ```
+//!
+//! Since weak and strong types are the same, use these descriptors with `no_weak_conversions`.
+//!
+//! [`Layout`] owns a `[u8; N]`. To view part of a larger buffer in place, or a buffer whose length is only known at runtime,
+//! use [`SliceLayout`] over a `&[u8]` or `&mut [u8]` instead:
+//!
+//! ```
+//! # use faible::{binary, faible};
+//! # pub struct Error;
+//! # impl faible::Error for Error {
+//! #     fn no_variant_recognized() -> Self { Self }
+//! # }
+//! #[faible(
+//!     binary::SliceLayout::<Error, &'a mut [u8]>::new(),
+//!     weak = &'a mut [u8],
+//!     strong = &'a mut [u8],
+//!     names = index,
+//!     no_weak_conversions,
+//! )]
+//! pub struct Header<'a> {
+//!     #[faible(_, name = 0x0)]
+//!     pub tag: u8,
+//! }
+//!
+//! let mut packet = [7, 0, 0xFF, 0xFF];
+//! let mut header = Header::from(&mut packet[..2]);
+//! header.set_tag(8).ok();
+//! assert_eq!(packet, [8, 0, 0xFF, 0xFF]);
+//! ```
+
+use crate::{Descriptor, Error, FieldAccess, View};
+use core::{fmt, marker::PhantomData, mem};
+
+/// Plain data that can be borrowed directly from a byte buffer at any offset.
+///
+/// # Safety
+///
+/// Implementors must have an alignment of 1 and no padding,
+/// and any bit pattern of their size must be a valid instance.
+pub unsafe trait Plain: Copy {}
+
+/// # Safety
+///
+/// A byte is trivially plain.
+unsafe impl Plain for u8 {}
+
+/// # Safety
+///
+/// Same size and alignment as [`u8`], and all bit patterns are valid.
+unsafe impl Plain for i8 {}
+
+/// # Safety
+///
+/// Arrays have the alignment of their elements and no padding between them.
+unsafe impl<T: Plain, const N: usize> Plain for [T; N] {}
+
+/// Describes a byte buffer of length `N`, with fields named by `usize` byte offsets.
+///
+/// Field accesses are bounds-checked, and fail with [`Error::missing_field`] if the field would extend past the buffer's end.
+pub struct Layout<E, const N: usize>(PhantomData<fn() -> E>);
+impl<E, const N: usize> Layout<E, N> {
+	/// Creates a new instance of this descriptor.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<E, const N: usize> Default for Layout<E, N> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<E: Error, const N: usize> Descriptor for Layout<E, N> {
+	type Weak = [u8; N];
+	type Strong = [u8; N];
+	type Error = E;
+
+	fn strong<'a>(&self, weak: &'a Self::Weak) -> Result<&'a Self::Strong, Self::Error> {
+		Ok(weak)
+	}
+
+	fn strong_mut<'a>(
+		&self,
+		weak: &'a mut Self::Weak,
+	) -> Result<&'a mut Self::Strong, Self::Error> {
+		Ok(weak)
+	}
+
+	fn strong_into_weak(&self, strong: Self::Strong) -> Self::Weak {
+		strong
+	}
+
+	fn try_weak_into_strong(&self, weak: Self::Weak) -> Result<Self::Strong, Self::Error> {
+		Ok(weak)
+	}
+}
+
+fn field<T: Plain, E: Error>(strong: &[u8], offset: usize) -> Result<&[u8], E> {
+	offset
+		.checked_add(mem::size_of::<T>())
+		.and_then(|end| strong.get(offset..end))
+		.ok_or_else(|| E::missing_field(&offset))
+}
+
+fn field_mut<T: Plain, E: Error>(strong: &mut [u8], offset: usize) -> Result<&mut [u8], E> {
+	offset
+		.checked_add(mem::size_of::<T>())
+		.and_then(move |end| strong.get_mut(offset..end))
+		.ok_or_else(|| E::missing_field(&offset))
+}
+
+impl<E: Error, T: Plain, const N: usize> FieldAccess<[u8; N], E, T, usize> for Layout<E, N> {
+	fn get<'a>(&self, strong: &'a [u8; N], offset: usize) -> Result<&'a T, E> {
+		let bytes = field::<T, E>(strong, offset)?;
+		// SAFETY: `bytes` is exactly as long as `T`, which is `Plain`.
+		Ok(unsafe { &*bytes.as_ptr().cast::<T>() })
+	}
+
+	fn get_mut<'a>(&self, strong: &'a mut [u8; N], offset: usize) -> Result<&'a mut T, E> {
+		let bytes = field_mut::<T, E>(strong, offset)?;
+		// SAFETY: `bytes` is exactly as long as `T`, which is `Plain`.
+		Ok(unsafe { &mut *bytes.as_mut_ptr().cast::<T>() })
+	}
+
+	fn set(&self, strong: &mut [u8; N], offset: usize, value: T) -> Result<(), E> {
+		*self.get_mut(strong, offset)? = value;
+		Ok(())
+	}
+
+	fn insert<'a>(
+		&self,
+		strong: &'a mut [u8; N],
+		offset: usize,
+		value: T,
+	) -> Result<(&'a mut T, Option<T>), E> {
+		let slot: &mut T = self.get_mut(strong, offset)?;
+		let prev = mem::replace(slot, value);
+		Ok((slot, Some(prev)))
+	}
+}
+
+/// Byte buffers that a [`SliceLayout`] can describe.
+pub trait Bytes {
+	/// Borrows the buffer's bytes.
+	fn bytes(&self) -> &[u8];
+
+	/// Mutably borrows the buffer's bytes, or returns [`None`] iff the buffer is read-only.
+	fn bytes_mut(&mut self) -> Option<&mut [u8]>;
+}
+
+impl Bytes for &[u8] {
+	fn bytes(&self) -> &[u8] {
+		self
+	}
+
+	fn bytes_mut(&mut self) -> Option<&mut [u8]> {
+		None
+	}
+}
+
+impl Bytes for &mut [u8] {
+	fn bytes(&self) -> &[u8] {
+		self
+	}
+
+	fn bytes_mut(&mut self) -> Option<&mut [u8]> {
+		Some(self)
+	}
+}
+
+/// Describes a byte buffer `B` of any length, with fields named by `usize` byte offsets.
+///
+/// Field accesses are bounds-checked like with [`Layout`].
+/// Mutable accesses through a read-only `&[u8]` fail with [`Error::read_only_field`].
+pub struct SliceLayout<E, B>(PhantomData<fn() -> (E, B)>);
+impl<E, B> SliceLayout<E, B> {
+	/// Creates a new instance of this descriptor.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<E, B> Default for SliceLayout<E, B> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<E: Error, B: Bytes> Descriptor for SliceLayout<E, B> {
+	type Weak = B;
+	type Strong = B;
+	type Error = E;
+
+	fn strong<'a>(&self, weak: &'a Self::Weak) -> Result<&'a Self::Strong, Self::Error> {
+		Ok(weak)
+	}
+
+	fn strong_mut<'a>(
+		&self,
+		weak: &'a mut Self::Weak,
+	) -> Result<&'a mut Self::Strong, Self::Error> {
+		Ok(weak)
+	}
+
+	fn strong_into_weak(&self, strong: Self::Strong) -> Self::Weak {
+		strong
+	}
+
+	fn try_weak_into_strong(&self, weak: Self::Weak) -> Result<Self::Strong, Self::Error> {
+		Ok(weak)
+	}
+}
+
+impl<E: Error, B: Bytes, T: Plain> FieldAccess<B, E, T, usize> for SliceLayout<E, B> {
+	fn get<'a>(&self, strong: &'a B, offset: usize) -> Result<&'a T, E> {
+		let bytes = field::<T, E>(strong.bytes(), offset)?;
+		// SAFETY: `bytes` is exactly as long as `T`, which is `Plain`.
+		Ok(unsafe { &*bytes.as_ptr().cast::<T>() })
+	}
+
+	fn get_mut<'a>(&self, strong: &'a mut B, offset: usize) -> Result<&'a mut T, E> {
+		let bytes = strong
+			.bytes_mut()
+			.ok_or_else(|| E::read_only_field(&offset))?;
+		let bytes = field_mut::<T, E>(bytes, offset)?;
+		// SAFETY: `bytes` is exactly as long as `T`, which is `Plain`.
+		Ok(unsafe { &mut *bytes.as_mut_ptr().cast::<T>() })
+	}
+
+	fn set(&self, strong: &mut B, offset: usize, value: T) -> Result<(), E> {
+		*self.get_mut(strong, offset)? = value;
+		Ok(())
+	}
+
+	fn insert<'a>(
+		&self,
+		strong: &'a mut B,
+		offset: usize,
+		value: T,
+	) -> Result<(&'a mut T, Option<T>), E> {
+		let slot: &mut T = self.get_mut(strong, offset)?;
+		let prev = mem::replace(slot, value);
+		Ok((slot, Some(prev)))
+	}
+}
+
+macro_rules! endian_integers {
+	($(
+		$(#[$attr:meta])*
+		$name:ident($ty:ty, $from_bytes:ident, $to_bytes:ident)
+	),*$(,)?) => {$(
+		$(#[$attr])*
+		#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+		#[repr(transparent)]
+		pub struct $name([u8; mem::size_of::<$ty>()]);
+
+		impl $name {
+			/// Encodes `value`.
+			#[must_use]
+			pub const fn new(value: $ty) -> Self {
+				Self(value.$to_bytes())
+			}
+
+			/// Decodes the stored value.
+			#[must_use]
+			pub const fn get(self) -> $ty {
+				<$ty>::$from_bytes(self.0)
+			}
+
+			/// Encodes and stores `value`.
+			pub fn set(&mut self, value: $ty) {
+				*self = Self::new(value);
+			}
+		}
+
+		/// # Safety
+		///
+		/// This is a `#[repr(transparent)]` byte array.
+		unsafe impl Plain for $name {}
+
+		/// # Safety
+		///
+		/// This is a `#[repr(transparent)]` wrapper.
+		unsafe impl View<[u8; mem::size_of::<$ty>()]> for $name {}
+
+		impl From<$ty> for $name {
+			fn from(value: $ty) -> Self {
+				Self::new(value)
+			}
+		}
+
+		impl From<$name> for $ty {
+			fn from(value: $name) -> Self {
+				value.get()
+			}
+		}
+
+		impl fmt::Debug for $name {
+			fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+				self.get().fmt(f)
+			}
+		}
+	)*};
+}
+
+endian_integers! {
+	/// A little-endian [`u16`].
+	U16Le(u16, from_le_bytes, to_le_bytes),
+	/// A big-endian [`u16`].
+	U16Be(u16, from_be_bytes, to_be_bytes),
+	/// A little-endian [`u32`].
+	U32Le(u32, from_le_bytes, to_le_bytes),
+	/// A big-endian [`u32`].
+	U32Be(u32, from_be_bytes, to_be_bytes),
+	/// A little-endian [`u64`].
+	U64Le(u64, from_le_bytes, to_le_bytes),
+	/// A big-endian [`u64`].
+	U64Be(u64, from_be_bytes, to_be_bytes),
+	/// A little-endian [`i16`].
+	I16Le(i16, from_le_bytes, to_le_bytes),
+	/// A big-endian [`i16`].
+	I16Be(i16, from_be_bytes, to_be_bytes),
+	/// A little-endian [`i32`].
+	I32Le(i32, from_le_bytes, to_le_bytes),
+	/// A big-endian [`i32`].
+	I32Be(i32, from_be_bytes, to_be_bytes),
+	/// A little-endian [`i64`].
+	I64Le(i64, from_le_bytes, to_le_bytes),
+	/// A big-endian [`i64`].
+	I64Be(i64, from_be_bytes, to_be_bytes),
+}
@@ -0,0 +1,178 @@
+//! A descriptor for integers with several fields packed into them, like hardware registers or protocol flags.
+//!
+//! Fields are named by the `Range<u32>` of bits they occupy, counted from the least significant bit.
+//! Bits can't be borrowed, so fields are read and written by value through [`FieldValueAccess`],
+//! and need `#[faible(by_value)]`:
+//!
+//! ```
+//! use faible::{bits, faible};
+//! # pub struct Error;
+//! # impl faible::Error for Error {
+//! #     fn no_variant_recognized() -> Self { Self }
+//! # }
+//!
+//! #[faible(bits::Packed::<u8, Error>::new(), weak = u8, strong = u8, names = index, no_weak_conversions)]
+//! pub struct Flags {
+//!     #[faible(by_value, name = 0..1)]
+//!     pub enabled: bool,
+//!     #[faible(by_value, name = 4..8)]
+//!     pub mode: u8,
+//! }
+//!
+//! let mut flags = Flags::from(0b0101_0001);
+//! assert_eq!(flags.enabled().ok(), Some(true));
+//! assert_eq!(flags.mode().ok(), Some(0b0101));
+//!
+//! flags.set_mode(0b1010).ok();
+//! assert_eq!(flags.0, 0b1010_0001);
+//! ```
+
+use crate::{Descriptor, Error, FieldValueAccess};
+use core::{fmt::Debug, marker::PhantomData, ops::Range};
+
+/// Unsigned integers that [`Packed`] can describe.
+///
+/// This is sealed and implemented for [`u8`], [`u16`], [`u32`] and [`u64`], so that [`Word::BITS`] never exceeds 64.
+pub trait Word: sealed::Sealed + Copy + Debug {
+	/// The number of bits in this integer.
+	const BITS: u32;
+
+	/// Widens `self` without changing its value.
+	fn into_u64(self) -> u64;
+
+	/// Truncates `value` to this integer's width.
+	fn from_u64(value: u64) -> Self;
+}
+
+/// Values that can be stored in a bit range.
+pub trait Field: Sized + Debug {
+	/// Decodes a field from its `bits`, which are already shifted down and masked.
+	///
+	/// Returns [`None`] iff `bits` isn't a valid value of `Self`.
+	fn from_bits(bits: u64) -> Option<Self>;
+
+	/// Encodes `self` as unshifted bits.
+	fn into_bits(self) -> u64;
+}
+
+impl Field for bool {
+	fn from_bits(bits: u64) -> Option<Self> {
+		Some(bits != 0)
+	}
+
+	fn into_bits(self) -> u64 {
+		self.into()
+	}
+}
+
+mod sealed {
+	pub trait Sealed {}
+}
+
+macro_rules! integers {
+	($($ty:ty),*$(,)?) => {$(
+		impl sealed::Sealed for $ty {}
+
+		impl Word for $ty {
+			#[allow(clippy::cast_possible_truncation)]
+			const BITS: u32 = (core::mem::size_of::<$ty>() * 8) as u32;
+
+			fn into_u64(self) -> u64 {
+				self.into()
+			}
+
+			#[allow(clippy::cast_possible_truncation)]
+			fn from_u64(value: u64) -> Self {
+				value as $ty
+			}
+		}
+
+		impl Field for $ty {
+			fn from_bits(bits: u64) -> Option<Self> {
+				use core::convert::TryFrom;
+				Self::try_from(bits).ok()
+			}
+
+			fn into_bits(self) -> u64 {
+				self.into()
+			}
+		}
+	)*};
+}
+integers!(u8, u16, u32, u64);
+
+/// Describes an integer `I` with fields packed into it, named by bit ranges.
+///
+/// A field's range must be non-empty and lie within `I`, or accesses fail with [`Error::missing_field`].
+/// Values that don't fit into their range are rejected with [`Error::out_of_range`].
+///
+/// Since weak and strong types are the same, use it with `no_weak_conversions`.
+pub struct Packed<I, E>(PhantomData<fn() -> (I, E)>);
+impl<I, E> Packed<I, E> {
+	/// Creates a new instance of this descriptor.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<I, E> Default for Packed<I, E> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<I: Word, E: Error> Descriptor for Packed<I, E> {
+	type Weak = I;
+	type Strong = I;
+	type Error = E;
+
+	fn strong<'a>(&self, weak: &'a Self::Weak) -> Result<&'a Self::Strong, Self::Error> {
+		Ok(weak)
+	}
+
+	fn strong_mut<'a>(
+		&self,
+		weak: &'a mut Self::Weak,
+	) -> Result<&'a mut Self::Strong, Self::Error> {
+		Ok(weak)
+	}
+
+	fn strong_into_weak(&self, strong: Self::Strong) -> Self::Weak {
+		strong
+	}
+
+	fn try_weak_into_strong(&self, weak: Self::Weak) -> Result<Self::Strong, Self::Error> {
+		Ok(weak)
+	}
+}
+
+impl<I: Word, E: Error> Packed<I, E> {
+	/// Returns the unshifted mask of `bits`.
+	fn mask(bits: &Range<u32>) -> Result<u64, E> {
+		if bits.start < bits.end && bits.end <= I::BITS {
+			Ok(u64::MAX >> (64 - (bits.end - bits.start)))
+		} else {
+			Err(E::missing_field(bits))
+		}
+	}
+}
+
+/// Fails iff `bits` is empty or out of range, or the field's value isn't valid for `T` or doesn't fit into `bits`.
+impl<I: Word, E: Error, T: Field> FieldValueAccess<I, E, T, Range<u32>> for Packed<I, E> {
+	fn get(&self, strong: &I, bits: Range<u32>) -> Result<T, E> {
+		let mask = Self::mask(&bits)?;
+		let value = strong.into_u64() >> bits.start & mask;
+		T::from_bits(value).ok_or_else(|| E::type_mismatch(core::any::type_name::<T>(), &value))
+	}
+
+	fn set(&self, strong: &mut I, bits: Range<u32>, value: T) -> Result<(), E> {
+		let mask = Self::mask(&bits)?;
+		let value = value.into_bits();
+		if value & !mask != 0 {
+			return Err(E::out_of_range("value fitting the bit range", &value));
+		}
+		*strong = I::from_u64(strong.into_u64() & !(mask << bits.start) | value << bits.start);
+		Ok(())
+	}
+}
@@ -0,0 +1,207 @@
+//! Descriptors over [`Bson`] values and [`bson::Document`](`::bson::Document`)s, for MongoDB-shaped data.
+//!
+//! Descriptors here are generic over the [`Error`] type `E`. Specify it along with the
+//! [`weak` and `strong` types](crate::faible#weak-and-strong-types):
+//!
+//! ```
+//! use ::bson::{oid::ObjectId, Bson, Document};
+//! use faible::{bson, faible, Faible};
+//! # pub struct Error;
+//! # impl faible::Error for Error {
+//! #     fn no_variant_recognized() -> Self { Self }
+//! # }
+//!
+//! #[faible(
+//!     bson::Root::<Error>::new(),
+//!     weak = Document,
+//!     strong = Document,
+//!     names = "verbatim",
+//!     no_weak_conversions,
+//! )]
+//! pub struct User {
+//!     pub _id: Id,
+//!     pub profile: Profile,
+//! }
+//!
+//! #[faible(
+//!     bson::Document::<Error>::new(),
+//!     weak = Bson,
+//!     strong = Document,
+//!     names = "lowerCamelCase",
+//! )]
+//! pub struct Profile {
+//!     pub display_name: Bson,
+//! }
+//!
+//! #[faible(bson::ObjectId::<Error>::new(), weak = Bson, strong = ObjectId)]
+//! pub struct Id;
+//!
+//! let id = ObjectId::new();
+//! let user = User::from(::bson::doc! { "_id": id, "profile": { "displayName": "Ferris" } });
+//! assert_eq!(user._id().ok().and_then(|id| id.as_strong().ok()), Some(&id));
+//! ```
+
+use crate::{Descriptor, Error, FieldAccess, View};
+use ::bson::Bson;
+use core::marker::PhantomData;
+
+macro_rules! bson_descriptors {
+	($(
+		$(#[$attr:meta])*
+		$name:ident($variant:ident, $strong:ty, $expected:literal)
+	),*$(,)?) => {$(
+		$(#[$attr])*
+		pub struct $name<E>(PhantomData<fn() -> E>);
+		impl<E> $name<E> {
+			/// Creates a new instance of this descriptor.
+			#[must_use]
+			pub const fn new() -> Self {
+				Self(PhantomData)
+			}
+		}
+
+		impl<E> Default for $name<E> {
+			fn default() -> Self {
+				Self::new()
+			}
+		}
+
+		impl<E: Error> Descriptor for $name<E> {
+			type Weak = Bson;
+			type Strong = $strong;
+			type Error = E;
+
+			fn strong<'a>(&self, weak: &'a Self::Weak) -> Result<&'a Self::Strong, Self::Error> {
+				match weak {
+					Bson::$variant(strong) => Ok(strong),
+					weak => Err(E::type_mismatch($expected, weak)),
+				}
+			}
+
+			fn strong_mut<'a>(
+				&self,
+				weak: &'a mut Self::Weak,
+			) -> Result<&'a mut Self::Strong, Self::Error> {
+				match weak {
+					Bson::$variant(strong) => Ok(strong),
+					weak => Err(E::type_mismatch($expected, weak)),
+				}
+			}
+
+			fn strong_into_weak(&self, strong: Self::Strong) -> Self::Weak {
+				Bson::$variant(strong)
+			}
+
+			fn try_weak_into_strong(&self, weak: Self::Weak) -> Result<Self::Strong, Self::Error> {
+				match weak {
+					Bson::$variant(strong) => Ok(strong),
+					weak => Err(E::type_mismatch($expected, &weak)),
+				}
+			}
+		}
+	)*};
+}
+
+bson_descriptors! {
+	/// Describes an embedded document, with fields named by `&str` keys.
+	Document(Document, ::bson::Document, "document"),
+	/// Describes an [`ObjectId`](`::bson::oid::ObjectId`).
+	ObjectId(ObjectId, ::bson::oid::ObjectId, "object ID"),
+	/// Describes a [`DateTime`](`::bson::DateTime`).
+	DateTime(DateTime, ::bson::DateTime, "date-time"),
+	/// Describes a [`Binary`](`::bson::Binary`) value, including its subtype.
+	Binary(Binary, ::bson::Binary, "binary"),
+	/// Describes a [`Decimal128`](`::bson::Decimal128`).
+	Decimal128(Decimal128, ::bson::Decimal128, "decimal128"),
+}
+
+/// Describes a top-level [`bson::Document`](`::bson::Document`), like the ones returned by the MongoDB driver.
+///
+/// This is [`Document`] for values that aren't wrapped in [`Bson`].
+/// Since its weak and strong types are the same, use it with `no_weak_conversions`.
+pub struct Root<E>(PhantomData<fn() -> E>);
+impl<E> Root<E> {
+	/// Creates a new instance of this descriptor.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<E> Default for Root<E> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<E: Error> Descriptor for Root<E> {
+	type Weak = ::bson::Document;
+	type Strong = ::bson::Document;
+	type Error = E;
+
+	fn strong<'a>(&self, weak: &'a Self::Weak) -> Result<&'a Self::Strong, Self::Error> {
+		Ok(weak)
+	}
+
+	fn strong_mut<'a>(
+		&self,
+		weak: &'a mut Self::Weak,
+	) -> Result<&'a mut Self::Strong, Self::Error> {
+		Ok(weak)
+	}
+
+	fn strong_into_weak(&self, strong: Self::Strong) -> Self::Weak {
+		strong
+	}
+
+	fn try_weak_into_strong(&self, weak: Self::Weak) -> Result<Self::Strong, Self::Error> {
+		Ok(weak)
+	}
+}
+
+macro_rules! document_field_access {
+	($($name:ident),*$(,)?) => {$(
+		impl<'n, E: Error, T: View<Bson>> FieldAccess<::bson::Document, E, T, &'n str> for $name<E> {
+			fn get<'a>(&self, strong: &'a ::bson::Document, name: &'n str) -> Result<&'a T, E> {
+				strong
+					.get(name)
+					.map(T::from_ref)
+					.ok_or_else(|| E::missing_field(&name))
+			}
+
+			fn get_mut<'a>(
+				&self,
+				strong: &'a mut ::bson::Document,
+				name: &'n str,
+			) -> Result<&'a mut T, E> {
+				strong
+					.get_mut(name)
+					.map(T::from_mut)
+					.ok_or_else(|| E::missing_field(&name))
+			}
+
+			fn set(&self, strong: &mut ::bson::Document, name: &'n str, value: T) -> Result<(), E>
+			where
+				T: Sized,
+			{
+				strong.insert(name, value.into_inner());
+				Ok(())
+			}
+
+			fn insert<'a>(
+				&self,
+				strong: &'a mut ::bson::Document,
+				name: &'n str,
+				value: T,
+			) -> Result<(&'a mut T, Option<T>), E>
+			where
+				T: Sized,
+			{
+				let prev = strong.insert(name, value.into_inner());
+				let slot = strong.get_mut(name).expect("unreachable");
+				Ok(T::from_insertion((slot, prev)))
+			}
+		}
+	)*};
+}
+document_field_access!(Document, Root);
@@ -0,0 +1,118 @@
+//! Descriptors over [`JsValue`], for JavaScript values passed into WebAssembly.
+//!
+//! The descriptors take the [`Error`] type `E` as parameter, and `#[faible(…)]` should name
+//! their [`weak` and `strong` types](crate::faible#weak-and-strong-types):
+//!
+//! ```
+//! use faible::{faible, js};
+//! use wasm_bindgen::JsValue;
+//! # pub struct Error;
+//! # impl faible::Error for Error {
+//! #     fn no_variant_recognized() -> Self { Self }
+//! # }
+//!
+//! #[faible(js::Object::<Error>::new(), weak = JsValue, strong = js_sys::Object)]
+//! pub struct Options;
+//! ```
+//!
+//! JavaScript properties can't be borrowed, so fields are read and written by value
+//! through [`FieldValueAccess`], and need `#[faible(by_value)]`.
+//! Since [`JsValue`]s are handles, changes made through a returned value are visible through the original object too.
+
+use crate::{Descriptor, Error, FieldValueAccess, View};
+use core::{any::type_name, fmt::Debug, marker::PhantomData, mem::size_of, ptr::NonNull};
+use js_sys::Reflect;
+use wasm_bindgen::{JsCast, JsValue};
+
+/// Describes a [`JsValue`] that can be cast to `T`, as checked by [`JsCast::dyn_ref`].
+///
+/// Primitive strings, numbers and booleans are recognised as
+/// [`js_sys::JsString`], [`js_sys::Number`] and [`js_sys::Boolean`], respectively.
+pub struct Cast<T, E>(PhantomData<fn() -> (T, E)>);
+impl<T, E> Cast<T, E> {
+	/// Creates a new instance of this descriptor.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<T, E> Default for Cast<T, E> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Describes a JavaScript object, including arrays and functions.
+pub type Object<E> = Cast<js_sys::Object, E>;
+/// Describes a JavaScript array.
+pub type Array<E> = Cast<js_sys::Array, E>;
+/// Describes a primitive JavaScript string.
+pub type String<E> = Cast<js_sys::JsString, E>;
+/// Describes a primitive JavaScript number.
+pub type Number<E> = Cast<js_sys::Number, E>;
+/// Describes a primitive JavaScript boolean.
+pub type Boolean<E> = Cast<js_sys::Boolean, E>;
+
+impl<T: JsCast, E: Error> Descriptor for Cast<T, E> {
+	type Weak = JsValue;
+	type Strong = T;
+	type Error = E;
+
+	fn strong<'a>(&self, weak: &'a Self::Weak) -> Result<&'a Self::Strong, Self::Error> {
+		weak.dyn_ref()
+			.ok_or_else(|| E::type_mismatch(type_name::<T>(), weak))
+	}
+
+	fn strong_mut<'a>(
+		&self,
+		weak: &'a mut Self::Weak,
+	) -> Result<&'a mut Self::Strong, Self::Error> {
+		// `JsCast` is a safe trait, so `T`'s layout is checked against the shared reference it hands out.
+		// This holds for all `#[wasm_bindgen]` imports, which are `#[repr(transparent)]` wrappers around `JsValue`.
+		if weak.has_type::<T>() && size_of::<T>() == size_of::<JsValue>() {
+			let shared: NonNull<T> = T::unchecked_from_js_ref(weak).into();
+			let exclusive: NonNull<JsValue> = (&mut *weak).into();
+			if shared.cast() == exclusive {
+				// SAFETY: `unchecked_from_js_ref` returned a `&T` spanning exactly `weak`'s bytes,
+				// so they are a valid and suitably aligned `T`, and `T` is stored in place of a `JsValue`.
+				// The exclusive borrow of `weak` is held for `'a`, so it can be handed out as `&'a mut T` instead.
+				return Ok(unsafe { exclusive.cast::<T>().as_mut() });
+			}
+		}
+		Err(E::type_mismatch(type_name::<T>(), weak))
+	}
+
+	fn strong_into_weak(&self, strong: Self::Strong) -> Self::Weak {
+		strong.into()
+	}
+
+	fn try_weak_into_strong(&self, weak: Self::Weak) -> Result<Self::Strong, Self::Error> {
+		weak.dyn_into()
+			.map_err(|weak| E::type_mismatch(type_name::<T>(), &weak))
+	}
+}
+
+/// Properties are accessed through [`Reflect::get`] and [`Reflect::set`].
+/// `N` can be anything that converts into a property key, like `&str` or `u32`.
+///
+/// Reading fails iff the property is `undefined` or its getter throws.
+/// Writing fails iff the property is read-only or its setter throws.
+impl<T: JsCast, E: Error, U: View<JsValue>, N: Copy + Debug + Into<JsValue>>
+	FieldValueAccess<T, E, U, N> for Cast<T, E>
+{
+	fn get(&self, strong: &T, name: N) -> Result<U, E> {
+		Reflect::get(strong.as_ref(), &name.into())
+			.ok()
+			.filter(|value| !value.is_undefined())
+			.map(U::from)
+			.ok_or_else(|| E::missing_field(&name))
+	}
+
+	fn set(&self, strong: &mut T, name: N, value: U) -> Result<(), E> {
+		match Reflect::set(strong.as_ref(), &name.into(), &value.into_inner()) {
+			Ok(true) => Ok(()),
+			Ok(false) | Err(_) => Err(E::read_only_field(&name)),
+		}
+	}
+}
@@ -0,0 +1,1536 @@
+//! Descriptors over [`serde_json::Value`], for JSON data.
+//!
+//! Each descriptor is generic over the [`Error`] type `E`, and is used with explicit
+//! [`weak` and `strong` types](crate::faible#weak-and-strong-types).
+//!
+//! Besides [`Object`] itself, there are field descriptors that convert values on access.
+//! They are used on individual `#[faible(by_value)]` fields of [`Object`]-described structs:
+//!
+//! ```
+//! use faible::{faible, json};
+//! use serde_json::{json, Map, Value};
+//! # pub struct Error;
+//! # impl faible::Error for Error {
+//! #     fn no_variant_recognized() -> Self { Self }
+//! # }
+//!
+//! #[faible(
+//!     json::Object::<Error>::new(),
+//!     weak = Value,
+//!     strong = Map<String, Value>,
+//!     names = "lowerCamelCase",
+//! )]
+//! pub struct Window {
+//!     pub title: Value,
+//!     #[faible(json::Number::<Error>::new(), by_value)]
+//!     pub scroll_x: u32,
+//! }
+//!
+//! let mut window = Window::from(json!({ "title": "faible", "scrollX": 12 }));
+//! assert_eq!(window.scroll_x().ok(), Some(12));
+//!
+//! window.set_scroll_x(34).ok();
+//! assert_eq!(window.0, json!({ "title": "faible", "scrollX": 34 }));
+//! ```
+//!
+//! `#[faible]` enums can use the same tagging styles as Serde, through [`InternallyTagged`],
+//! [`AdjacentlyTagged`], [`ExternallyTagged`] and [`StringUnit`].
+//! C-like enums can also be stored as numbers through [`IntegerUnit`].
+//! [`Untagged`] enums are recognised by the [`Shape`] of their fields instead,
+//! and so are the fields of `#[faible]` unions described by [`Union`].
+//!
+//! Fields shared by all variants of an [`InternallyTagged`] or [`AdjacentlyTagged`] enum,
+//! like an envelope's `id`, can be declared once in a `#[faible(common)]` pseudo-variant.
+//! They get the same accessors as struct fields, without matching on the variant:
+//!
+//! ```
+//! use faible::{faible, json};
+//! use serde_json::{json, Value};
+//! # pub struct Error;
+//! # impl faible::Error for Error {
+//! #     fn no_variant_recognized() -> Self { Self }
+//! # }
+//!
+//! #[faible(
+//!     json::AdjacentlyTagged::<Error>::new("t", "c"),
+//!     weak = Value,
+//!     strong = serde_json::Map<String, Value>,
+//!     names = "snake_case",
+//!     nested_names = "lowerCamelCase",
+//! )]
+//! pub enum Envelope {
+//!     #[faible(common)]
+//!     Common { pub id: Value, pub sent_at: Value },
+//!     Ping,
+//!     Text(#[faible(_, name = ())] Value),
+//! }
+//!
+//! let mut envelope = Envelope::from(json!({ "id": 7, "sentAt": 0, "t": "ping" }));
+//! assert_eq!(envelope.id().ok(), Some(&json!(7)));
+//!
+//! envelope.set_sent_at(json!(1)).ok();
+//! assert_eq!(envelope.0["sentAt"], 1);
+//! ```
+
+use crate::{
+	Classification, Descriptor, Error, FieldAccess, FieldPresence, FieldValueAccess, Probing,
+	UnionFieldAccess, UnitVariantAccess, VariantFieldAccessMut, VariantFieldAccessRef,
+	VariantFilter, VersionAccess, View,
+};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::{any::type_name, convert::TryInto, marker::PhantomData, mem};
+use serde_json::{map::Entry, Map, Number as JsonNumber, Value};
+
+/// Describes a JSON object, with fields named by `&str` keys.
+///
+/// Setting an absent field adds it to the object.
+pub struct Object<E>(PhantomData<fn() -> E>);
+impl<E> Object<E> {
+	/// Creates a new instance of this descriptor.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<E> Default for Object<E> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<E: Error> Descriptor for Object<E> {
+	type Weak = Value;
+	type Strong = Map<String, Value>;
+	type Error = E;
+
+	fn strong<'a>(&self, weak: &'a Self::Weak) -> Result<&'a Self::Strong, Self::Error> {
+		match weak {
+			Value::Object(strong) => Ok(strong),
+			weak => Err(E::type_mismatch("object", weak)),
+		}
+	}
+
+	fn strong_mut<'a>(
+		&self,
+		weak: &'a mut Self::Weak,
+	) -> Result<&'a mut Self::Strong, Self::Error> {
+		match weak {
+			Value::Object(strong) => Ok(strong),
+			weak => Err(E::type_mismatch("object", weak)),
+		}
+	}
+
+	fn strong_into_weak(&self, strong: Self::Strong) -> Self::Weak {
+		Value::Object(strong)
+	}
+
+	fn try_weak_into_strong(&self, weak: Self::Weak) -> Result<Self::Strong, Self::Error> {
+		match weak {
+			Value::Object(strong) => Ok(strong),
+			weak => Err(E::type_mismatch("object", &weak)),
+		}
+	}
+}
+
+impl<'n, E: Error, T: View<Value>> FieldAccess<Map<String, Value>, E, T, &'n str> for Object<E> {
+	fn get<'a>(&self, strong: &'a Map<String, Value>, name: &'n str) -> Result<&'a T, E> {
+		strong
+			.get(name)
+			.map(T::from_ref)
+			.ok_or_else(|| E::missing_field(&name))
+	}
+
+	fn get_mut<'a>(
+		&self,
+		strong: &'a mut Map<String, Value>,
+		name: &'n str,
+	) -> Result<&'a mut T, E> {
+		strong
+			.get_mut(name)
+			.map(T::from_mut)
+			.ok_or_else(|| E::missing_field(&name))
+	}
+
+	fn set(&self, strong: &mut Map<String, Value>, name: &'n str, value: T) -> Result<(), E> {
+		strong.insert(name.into(), value.into_inner());
+		Ok(())
+	}
+
+	fn insert<'a>(
+		&self,
+		strong: &'a mut Map<String, Value>,
+		name: &'n str,
+		value: T,
+	) -> Result<(&'a mut T, Option<T>), E> {
+		let value = value.into_inner();
+		Ok(T::from_insertion(match strong.entry(name) {
+			Entry::Vacant(vacant) => (vacant.insert(value), None),
+			Entry::Occupied(occupied) => {
+				let slot = occupied.into_mut();
+				let prev = mem::replace(slot, value);
+				(slot, Some(prev))
+			}
+		}))
+	}
+}
+
+impl<'n, E: Error> FieldPresence<Map<String, Value>, E, &'n str> for Object<E> {
+	fn contains(&self, strong: &Map<String, Value>, name: &'n str) -> Result<bool, E> {
+		Ok(strong.contains_key(name))
+	}
+
+	fn remove(&self, strong: &mut Map<String, Value>, name: &'n str) -> Result<(), E> {
+		strong.remove(name);
+		Ok(())
+	}
+}
+
+/// Versions are stored as unsigned integers.
+impl<'n, E: Error> VersionAccess<Map<String, Value>, E, &'n str> for Object<E> {
+	fn version(&self, strong: &Map<String, Value>, name: &'n str) -> Result<Option<u64>, E> {
+		strong
+			.get(name)
+			.map(|version| {
+				version
+					.as_u64()
+					.ok_or_else(|| E::type_mismatch("version number", version))
+			})
+			.transpose()
+	}
+
+	fn set_version(
+		&self,
+		strong: &mut Map<String, Value>,
+		name: &'n str,
+		version: u64,
+	) -> Result<(), E> {
+		strong.insert(name.into(), version.into());
+		Ok(())
+	}
+}
+
+/// Primitive numbers that [`Number`] can convert JSON numbers to and from.
+///
+/// Implemented for `u8` through `u64`, `i8` through `i64`, `f32` and `f64`.
+pub trait Numeric: Sized {
+	/// Converts `number` exactly, or for floats, to the nearest representable value.
+	///
+	/// # Errors
+	///
+	/// Iff `number` is out of range, or fractional where `Self` is an integer.
+	fn from_number<E: Error>(number: &serde_json::Number) -> Result<Self, E>;
+
+	/// Converts `self` into a JSON number.
+	///
+	/// # Errors
+	///
+	/// Iff `self` is NaN or infinite.
+	fn into_number<E: Error>(self) -> Result<serde_json::Number, E>;
+}
+
+macro_rules! integers {
+	($($ty:ty),*$(,)?) => {$(
+		impl Numeric for $ty {
+			#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss, clippy::float_cmp)]
+			fn from_number<E: Error>(number: &serde_json::Number) -> Result<Self, E> {
+				number
+					.as_i64()
+					.map(<i128 as From<i64>>::from)
+					.or_else(|| number.as_u64().map(<i128 as From<u64>>::from))
+					.or_else(|| {
+						// Saturates far outside the range of any `Self`, so only integral floats round-trip here.
+						let float = number.as_f64()?;
+						Some(float as i128).filter(|integer| *integer as f64 == float)
+					})
+					.and_then(|integer| integer.try_into().ok())
+					.ok_or_else(|| E::out_of_range(type_name::<$ty>(), number))
+			}
+
+			fn into_number<E: Error>(self) -> Result<serde_json::Number, E> {
+				Ok(self.into())
+			}
+		}
+	)*};
+}
+integers!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+impl Numeric for f64 {
+	fn from_number<E: Error>(number: &serde_json::Number) -> Result<Self, E> {
+		number
+			.as_f64()
+			.filter(|float| float.is_finite())
+			.ok_or_else(|| E::out_of_range("f64", number))
+	}
+
+	fn into_number<E: Error>(self) -> Result<serde_json::Number, E> {
+		serde_json::Number::from_f64(self).ok_or_else(|| E::out_of_range("finite number", &self))
+	}
+}
+
+impl Numeric for f32 {
+	#[allow(clippy::cast_possible_truncation)]
+	fn from_number<E: Error>(number: &serde_json::Number) -> Result<Self, E> {
+		number
+			.as_f64()
+			.map(|float| float as f32)
+			.filter(|float| float.is_finite())
+			.ok_or_else(|| E::out_of_range("f32", number))
+	}
+
+	fn into_number<E: Error>(self) -> Result<serde_json::Number, E> {
+		<f64 as From<f32>>::from(self).into_number()
+	}
+}
+
+/// Converts [`Object`] fields that are JSON numbers to and from [`Numeric`] primitives, with range checks.
+///
+/// Use it on `#[faible(by_value)]` fields, as in the [module](self) example.
+///
+/// Reading fails with [`Error::missing_field`] if the field is absent,
+/// with [`Error::type_mismatch`] if it isn't a number,
+/// and with [`Error::out_of_range`] if it doesn't fit the field type.
+/// Writing fails with [`Error::out_of_range`] if the value is NaN or infinite.
+pub struct Number<E>(PhantomData<fn() -> E>);
+impl<E> Number<E> {
+	/// Creates a new instance of this descriptor.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<E> Default for Number<E> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<'n, E: Error, T: Numeric> FieldValueAccess<Map<String, Value>, E, T, &'n str> for Number<E> {
+	fn get(&self, strong: &Map<String, Value>, name: &'n str) -> Result<T, E> {
+		match strong.get(name) {
+			Some(Value::Number(number)) => T::from_number(number),
+			Some(value) => Err(E::type_mismatch("number", value)),
+			None => Err(E::missing_field(&name)),
+		}
+	}
+
+	fn set(&self, strong: &mut Map<String, Value>, name: &'n str, value: T) -> Result<(), E> {
+		strong.insert(name.into(), Value::Number(value.into_number()?));
+		Ok(())
+	}
+}
+
+/// Converts [`Object`] fields that are JSON numbers or numeric strings to and from [`rust_decimal::Decimal`], exactly.
+///
+/// Use it on `#[faible(by_value)]` fields, as with [`Number`].
+///
+/// Numbers are only exact as parsed if the `arbitrary_precision` feature is enabled.
+/// Without it, they are read from their shortest round-tripping `f64` representation.
+///
+/// Strings are written back as strings, and other values as numbers.
+/// Without `arbitrary_precision`, writing a number that `f64` can't represent exactly, including its scale (like `1.10`),
+/// fails with [`Error::out_of_range`].
+#[cfg(feature = "rust_decimal")]
+pub struct Decimal<E>(PhantomData<fn() -> E>);
+#[cfg(feature = "rust_decimal")]
+impl<E> Decimal<E> {
+	/// Creates a new instance of this descriptor.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+#[cfg(feature = "rust_decimal")]
+impl<E> Default for Decimal<E> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[cfg(feature = "rust_decimal")]
+fn parse_decimal<E: Error>(text: &str, found: &Value) -> Result<rust_decimal::Decimal, E> {
+	use rust_decimal::Error as DecimalError;
+
+	if text.contains(['e', 'E']) {
+		rust_decimal::Decimal::from_scientific(text)
+	} else {
+		rust_decimal::Decimal::from_str_exact(text)
+	}
+	.map_err(|error| match error {
+		DecimalError::ErrorString(_) => E::type_mismatch("decimal", found),
+		_ => E::out_of_range("decimal", found),
+	})
+}
+
+#[cfg(feature = "rust_decimal")]
+impl<'n, E: Error> FieldValueAccess<Map<String, Value>, E, rust_decimal::Decimal, &'n str>
+	for Decimal<E>
+{
+	fn get(&self, strong: &Map<String, Value>, name: &'n str) -> Result<rust_decimal::Decimal, E> {
+		use alloc::string::ToString;
+
+		match strong.get(name) {
+			Some(value @ Value::Number(number)) => parse_decimal(&number.to_string(), value),
+			Some(value @ Value::String(string)) => parse_decimal(string, value),
+			Some(value) => Err(E::type_mismatch("decimal", value)),
+			None => Err(E::missing_field(&name)),
+		}
+	}
+
+	fn set(
+		&self,
+		strong: &mut Map<String, Value>,
+		name: &'n str,
+		value: rust_decimal::Decimal,
+	) -> Result<(), E> {
+		use alloc::string::ToString;
+
+		let text = value.to_string();
+		let value = if let Some(Value::String(_)) = strong.get(name) {
+			Value::String(text)
+		} else {
+			let number: serde_json::Number = text
+				.parse()
+				.map_err(|_| E::out_of_range("number", &value))?;
+			// `Decimal`'s equality ignores the scale, so trailing zeros are compared separately.
+			if !cfg!(feature = "arbitrary_precision")
+				&& parse_decimal::<E>(&number.to_string(), &Value::Null).map_or(true, |parsed| {
+					parsed != value || parsed.scale() != value.scale()
+				}) {
+				return Err(E::out_of_range("exactly representable number", &value));
+			}
+			Value::Number(number)
+		};
+		strong.insert(name.into(), value);
+		Ok(())
+	}
+}
+
+/// Converts [`Object`] fields that are JSON strings to and from [`time::OffsetDateTime`], using the format `F`.
+///
+/// Use it on `#[faible(by_value)]` fields, as with [`Number`].
+/// `F` is used for both reading and writing, so writes keep the original style:
+///
+/// ```
+/// use faible::{faible, json};
+/// use serde_json::{json, Map, Value};
+/// use time::{format_description::well_known::Rfc3339, macros::datetime, OffsetDateTime};
+/// # pub struct Error;
+/// # impl faible::Error for Error {
+/// #     fn no_variant_recognized() -> Self { Self }
+/// # }
+///
+/// #[faible(
+///     json::Object::<Error>::new(),
+///     weak = Value,
+///     strong = Map<String, Value>,
+///     names = "lowerCamelCase",
+/// )]
+/// pub struct Post {
+///     #[faible(json::DateTime::<_, Error>::new(Rfc3339), by_value)]
+///     pub created_at: OffsetDateTime,
+/// }
+///
+/// let mut post = Post::from(json!({ "createdAt": "2022-05-01T12:30:00+02:00" }));
+/// assert_eq!(post.created_at().ok(), Some(datetime!(2022-05-01 12:30 +2)));
+///
+/// post.set_created_at(datetime!(2022-05-02 08:00 UTC)).ok();
+/// assert_eq!(post.0, json!({ "createdAt": "2022-05-02T08:00:00Z" }));
+/// ```
+///
+/// Reading fails with [`Error::type_mismatch`] if the field isn't a string in this format.
+/// Writing fails with [`Error::out_of_range`] if the value can't be formatted, like years past 9999 in RFC 3339.
+#[cfg(feature = "time")]
+pub struct DateTime<F, E>(F, PhantomData<fn() -> E>);
+#[cfg(feature = "time")]
+impl<F, E> DateTime<F, E> {
+	/// Creates a new instance of this descriptor that reads and writes `format`.
+	#[must_use]
+	pub const fn new(format: F) -> Self {
+		Self(format, PhantomData)
+	}
+}
+
+#[cfg(feature = "time")]
+impl<'n, E: Error, F: time::formatting::Formattable + time::parsing::Parsable>
+	FieldValueAccess<Map<String, Value>, E, time::OffsetDateTime, &'n str> for DateTime<F, E>
+{
+	fn get(&self, strong: &Map<String, Value>, name: &'n str) -> Result<time::OffsetDateTime, E> {
+		match strong.get(name) {
+			Some(value @ Value::String(string)) => time::OffsetDateTime::parse(string, &self.0)
+				.map_err(|_| E::type_mismatch("date-time string", value)),
+			Some(value) => Err(E::type_mismatch("date-time string", value)),
+			None => Err(E::missing_field(&name)),
+		}
+	}
+
+	fn set(
+		&self,
+		strong: &mut Map<String, Value>,
+		name: &'n str,
+		value: time::OffsetDateTime,
+	) -> Result<(), E> {
+		let formatted = value
+			.format(&self.0)
+			.map_err(|_| E::out_of_range("formattable date-time", &value))?;
+		strong.insert(name.into(), Value::String(formatted));
+		Ok(())
+	}
+}
+
+/// Converts [`Object`] fields that are JSON numbers to and from [`time::OffsetDateTime`], as Unix timestamps.
+///
+/// Use it on `#[faible(by_value)]` fields, as with [`Number`].
+/// Timestamps are integers, so writing truncates the value to whole seconds or milliseconds, towards the past.
+/// Read values are in UTC.
+///
+/// Reading fails with [`Error::type_mismatch`] if the field isn't a number,
+/// and with [`Error::out_of_range`] if it isn't an integer or can't be represented as [`time::OffsetDateTime`].
+#[cfg(feature = "time")]
+pub struct UnixTimestamp<E> {
+	nanos_per_unit: i128,
+	_phantom: PhantomData<fn() -> E>,
+}
+#[cfg(feature = "time")]
+impl<E> UnixTimestamp<E> {
+	/// Creates a new instance of this descriptor that counts seconds.
+	#[must_use]
+	pub const fn seconds() -> Self {
+		Self {
+			nanos_per_unit: 1_000_000_000,
+			_phantom: PhantomData,
+		}
+	}
+
+	/// Creates a new instance of this descriptor that counts milliseconds.
+	#[must_use]
+	pub const fn millis() -> Self {
+		Self {
+			nanos_per_unit: 1_000_000,
+			_phantom: PhantomData,
+		}
+	}
+}
+
+#[cfg(feature = "time")]
+impl<'n, E: Error> FieldValueAccess<Map<String, Value>, E, time::OffsetDateTime, &'n str>
+	for UnixTimestamp<E>
+{
+	fn get(&self, strong: &Map<String, Value>, name: &'n str) -> Result<time::OffsetDateTime, E> {
+		let units: i64 = Number::new().get(strong, name)?;
+		time::OffsetDateTime::from_unix_timestamp_nanos(
+			<i128 as From<i64>>::from(units) * self.nanos_per_unit,
+		)
+		.map_err(|_| E::out_of_range("Unix timestamp", &units))
+	}
+
+	#[allow(clippy::cast_possible_truncation)]
+	fn set(
+		&self,
+		strong: &mut Map<String, Value>,
+		name: &'n str,
+		value: time::OffsetDateTime,
+	) -> Result<(), E> {
+		// Always in range, since `OffsetDateTime` spans far less than ±2⁶³ milliseconds.
+		let units = value.unix_timestamp_nanos().div_euclid(self.nanos_per_unit) as i64;
+		Number::new().set(strong, name, units)
+	}
+}
+
+/// Converts [`Object`] fields that are JSON strings to and from `Vec<u8>`, as base64 using the engine `B`.
+///
+/// Use it on `#[faible(by_value)]` fields, as with [`Number`]:
+/// `#[faible(json::Base64::<Error>::standard(), by_value)]`.
+///
+/// Alphabets and padding are configured through [`Base64::new`]. To accept both padded and unpadded input, for example:
+///
+/// ```
+/// use base64::{alphabet, engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig}};
+/// use faible::json;
+/// # pub struct Error;
+///
+/// const LENIENT: json::Base64<Error> = json::Base64::new(GeneralPurpose::new(
+///     &alphabet::URL_SAFE,
+///     GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+/// ));
+/// ```
+///
+/// Reading fails with [`Error::type_mismatch`] if the field isn't a valid base64 string.
+/// The mismatch's `found` value is the field name and its value.
+#[cfg(feature = "base64")]
+pub struct Base64<E, B = base64::engine::GeneralPurpose>(B, PhantomData<fn() -> E>);
+#[cfg(feature = "base64")]
+impl<E, B> Base64<E, B> {
+	/// Creates a new instance of this descriptor that reads and writes base64 using `engine`.
+	#[must_use]
+	pub const fn new(engine: B) -> Self {
+		Self(engine, PhantomData)
+	}
+}
+
+#[cfg(feature = "base64")]
+impl<E> Base64<E> {
+	/// Creates a new instance of this descriptor using the standard alphabet, with padding.
+	#[must_use]
+	pub const fn standard() -> Self {
+		Self::new(base64::engine::general_purpose::STANDARD)
+	}
+
+	/// Creates a new instance of this descriptor using the standard alphabet, without padding.
+	#[must_use]
+	pub const fn standard_no_pad() -> Self {
+		Self::new(base64::engine::general_purpose::STANDARD_NO_PAD)
+	}
+
+	/// Creates a new instance of this descriptor using the URL-safe alphabet, with padding.
+	#[must_use]
+	pub const fn url_safe() -> Self {
+		Self::new(base64::engine::general_purpose::URL_SAFE)
+	}
+
+	/// Creates a new instance of this descriptor using the URL-safe alphabet, without padding.
+	#[must_use]
+	pub const fn url_safe_no_pad() -> Self {
+		Self::new(base64::engine::general_purpose::URL_SAFE_NO_PAD)
+	}
+}
+
+#[cfg(feature = "base64")]
+impl<'n, E: Error, B: base64::Engine> FieldValueAccess<Map<String, Value>, E, Vec<u8>, &'n str>
+	for Base64<E, B>
+{
+	fn get(&self, strong: &Map<String, Value>, name: &'n str) -> Result<Vec<u8>, E> {
+		match strong.get(name) {
+			Some(Value::String(encoded)) => self.0.decode(encoded).ok(),
+			Some(_) => None,
+			None => return Err(E::missing_field(&name)),
+		}
+		.ok_or_else(|| E::type_mismatch("base64 string", &(name, &strong[name])))
+	}
+
+	fn set(&self, strong: &mut Map<String, Value>, name: &'n str, value: Vec<u8>) -> Result<(), E> {
+		strong.insert(name.into(), Value::String(self.0.encode(value)));
+		Ok(())
+	}
+}
+
+/// Converts [`Object`] fields that are JSON strings to and from `Vec<u8>`, as hexadecimal digits.
+///
+/// Use it on `#[faible(by_value)]` fields, as with [`Number`]: `#[faible(json::Hex::<Error>::lower(), by_value)]`.
+///
+/// Either case is accepted when reading. The case used for writing is chosen by the constructor.
+///
+/// Reading fails with [`Error::type_mismatch`] if the field isn't a valid hexadecimal string.
+/// The mismatch's `found` value is the field name and its value.
+#[cfg(feature = "hex")]
+pub struct Hex<E> {
+	upper: bool,
+	_phantom: PhantomData<fn() -> E>,
+}
+#[cfg(feature = "hex")]
+impl<E> Hex<E> {
+	/// Creates a new instance of this descriptor that writes lowercase digits.
+	#[must_use]
+	pub const fn lower() -> Self {
+		Self {
+			upper: false,
+			_phantom: PhantomData,
+		}
+	}
+
+	/// Creates a new instance of this descriptor that writes uppercase digits.
+	#[must_use]
+	pub const fn upper() -> Self {
+		Self {
+			upper: true,
+			_phantom: PhantomData,
+		}
+	}
+}
+
+#[cfg(feature = "hex")]
+impl<'n, E: Error> FieldValueAccess<Map<String, Value>, E, Vec<u8>, &'n str> for Hex<E> {
+	fn get(&self, strong: &Map<String, Value>, name: &'n str) -> Result<Vec<u8>, E> {
+		match strong.get(name) {
+			Some(Value::String(encoded)) => hex::decode(encoded).ok(),
+			Some(_) => None,
+			None => return Err(E::missing_field(&name)),
+		}
+		.ok_or_else(|| E::type_mismatch("hexadecimal string", &(name, &strong[name])))
+	}
+
+	fn set(&self, strong: &mut Map<String, Value>, name: &'n str, value: Vec<u8>) -> Result<(), E> {
+		let encoded = if self.upper {
+			hex::encode_upper(value)
+		} else {
+			hex::encode(value)
+		};
+		strong.insert(name.into(), Value::String(encoded));
+		Ok(())
+	}
+}
+
+fn tag<'a, E: Error>(strong: &'a Map<String, Value>, tag: &str) -> Result<&'a str, E> {
+	match strong.get(tag) {
+		Some(Value::String(tag)) => Ok(tag),
+		Some(value) => Err(E::type_mismatch("string tag", value)),
+		None => Err(E::missing_field(&tag)),
+	}
+}
+
+/// Exclusive access to the fields of a recognised enum variant, which are handed out one at a time.
+///
+/// This is the [`VariantFilter::CommonMut`] of this module's enum descriptors.
+pub struct FieldsMut<'a>(FieldsMutInner<'a>);
+enum FieldsMutInner<'a> {
+	Whole(Option<&'a mut Value>),
+	Split(Vec<(&'a String, &'a mut Value)>),
+}
+
+impl<'a> FieldsMut<'a> {
+	fn whole(content: Option<&'a mut Value>) -> Self {
+		Self(FieldsMutInner::Whole(content))
+	}
+
+	fn split(strong: &'a mut Map<String, Value>) -> Self {
+		Self(FieldsMutInner::Split(strong.iter_mut().collect()))
+	}
+
+	fn field<E: Error>(&mut self, name: &str) -> Result<&'a mut Value, E> {
+		if let FieldsMutInner::Whole(content) = &mut self.0 {
+			match content.take() {
+				Some(Value::Object(strong)) => *self = Self::split(strong),
+				Some(content) => return Err(E::type_mismatch("object", content)),
+				None => return Err(E::missing_field(&name)),
+			}
+		}
+
+		match &mut self.0 {
+			FieldsMutInner::Split(fields) => fields
+				.iter()
+				.position(|(key, _)| *key == name)
+				.map(|index| fields.swap_remove(index).1)
+				.ok_or_else(|| E::missing_field(&name)),
+			FieldsMutInner::Whole(_) => unreachable!(),
+		}
+	}
+
+	fn content<E: Error>(&mut self) -> Result<&'a mut Value, E> {
+		match &mut self.0 {
+			FieldsMutInner::Whole(content) => content.take(),
+			FieldsMutInner::Split(_) => None,
+		}
+		.ok_or_else(|| E::missing_field(&()))
+	}
+}
+
+/// Describes an enum stored as a JSON object whose `tag` field names the variant,
+/// with the variant's fields beside it: `{ "type": "Move", "x": 1, "y": 2 }`.
+///
+/// Variants are named by `&str`, and so are their fields.
+pub struct InternallyTagged<E> {
+	tag: &'static str,
+	_phantom: PhantomData<fn() -> E>,
+}
+impl<E> InternallyTagged<E> {
+	/// Creates a new instance of this descriptor, with variants named by the field `tag`.
+	#[must_use]
+	pub const fn new(tag: &'static str) -> Self {
+		Self {
+			tag,
+			_phantom: PhantomData,
+		}
+	}
+}
+
+/// Describes an enum stored as a JSON object whose `tag` field names the variant,
+/// with the variant's data in its `content` field: `{ "t": "Move", "c": { "x": 1, "y": 2 } }`.
+///
+/// Variants are named by `&str`. Their fields are named by `&str` for fields of the content object,
+/// or by `()` for the content itself, which is absent for unit variants.
+pub struct AdjacentlyTagged<E> {
+	tag: &'static str,
+	content: &'static str,
+	_phantom: PhantomData<fn() -> E>,
+}
+impl<E> AdjacentlyTagged<E> {
+	/// Creates a new instance of this descriptor, with variants named by the field `tag` and their data in the field `content`.
+	#[must_use]
+	pub const fn new(tag: &'static str, content: &'static str) -> Self {
+		Self {
+			tag,
+			content,
+			_phantom: PhantomData,
+		}
+	}
+}
+
+/// Describes an enum stored as a JSON object with a single field, whose key names the variant
+/// and whose value is the variant's data: `{ "Move": { "x": 1, "y": 2 } }`.
+/// Unit variants can also be just their name as string: `"Stop"`.
+///
+/// Variants are named by `&str`. Their fields are named by `&str` for fields of the content object,
+/// or by `()` for the content itself, which is absent for unit variants.
+///
+/// Since its weak and strong types are the same, use it with `no_weak_conversions`.
+pub struct ExternallyTagged<E>(PhantomData<fn() -> E>);
+impl<E> ExternallyTagged<E> {
+	/// Creates a new instance of this descriptor.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<E> Default for ExternallyTagged<E> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Describes an enum of unit variants stored as JSON strings, like `"Stop"`.
+///
+/// Variants are named by `&str`. This descriptor supports `#[faible(…, set_variant)]`.
+pub struct StringUnit<E>(PhantomData<fn() -> E>);
+impl<E> StringUnit<E> {
+	/// Creates a new instance of this descriptor.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<E> Default for StringUnit<E> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Describes an enum of unit variants stored as JSON integers, like `2`.
+///
+/// Variants are named by `i64`, so `names = discriminant` works with C-like enums.
+/// Like [`StringUnit`], it supports `#[faible(…, set_variant)]`.
+pub struct IntegerUnit<E>(PhantomData<fn() -> E>);
+impl<E> IntegerUnit<E> {
+	/// Creates a new instance of this descriptor.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<E> Default for IntegerUnit<E> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+macro_rules! object_descriptor {
+	($($name:ident),*$(,)?) => {$(
+		impl<E: Error> Descriptor for $name<E> {
+			type Weak = Value;
+			type Strong = Map<String, Value>;
+			type Error = E;
+
+			fn strong<'a>(&self, weak: &'a Self::Weak) -> Result<&'a Self::Strong, Self::Error> {
+				Object::new().strong(weak)
+			}
+
+			fn strong_mut<'a>(
+				&self,
+				weak: &'a mut Self::Weak,
+			) -> Result<&'a mut Self::Strong, Self::Error> {
+				Object::new().strong_mut(weak)
+			}
+
+			fn strong_into_weak(&self, strong: Self::Strong) -> Self::Weak {
+				Value::Object(strong)
+			}
+
+			fn try_weak_into_strong(&self, weak: Self::Weak) -> Result<Self::Strong, Self::Error> {
+				Object::new().try_weak_into_strong(weak)
+			}
+		}
+
+		/// Accesses fields of the whole object, like `#[faible(common)]` fields, as [`Object`] does.
+		impl<'n, E: Error, T: View<Value>> FieldAccess<Map<String, Value>, E, T, &'n str> for $name<E> {
+			fn get<'a>(&self, strong: &'a Map<String, Value>, name: &'n str) -> Result<&'a T, E> {
+				FieldAccess::get(&Object::new(), strong, name)
+			}
+
+			fn get_mut<'a>(
+				&self,
+				strong: &'a mut Map<String, Value>,
+				name: &'n str,
+			) -> Result<&'a mut T, E> {
+				FieldAccess::get_mut(&Object::new(), strong, name)
+			}
+
+			fn set(&self, strong: &mut Map<String, Value>, name: &'n str, value: T) -> Result<(), E> {
+				FieldAccess::set(&Object::new(), strong, name, value)
+			}
+
+			fn insert<'a>(
+				&self,
+				strong: &'a mut Map<String, Value>,
+				name: &'n str,
+				value: T,
+			) -> Result<(&'a mut T, Option<T>), E> {
+				FieldAccess::insert(&Object::new(), strong, name, value)
+			}
+		}
+	)*};
+}
+object_descriptor!(InternallyTagged, AdjacentlyTagged);
+
+impl<E: Error> Descriptor for ExternallyTagged<E> {
+	type Weak = Value;
+	type Strong = Value;
+	type Error = E;
+
+	fn strong<'a>(&self, weak: &'a Self::Weak) -> Result<&'a Self::Strong, Self::Error> {
+		match weak {
+			Value::Object(_) | Value::String(_) => Ok(weak),
+			weak => Err(E::type_mismatch("object or string", weak)),
+		}
+	}
+
+	fn strong_mut<'a>(
+		&self,
+		weak: &'a mut Self::Weak,
+	) -> Result<&'a mut Self::Strong, Self::Error> {
+		match weak {
+			Value::Object(_) | Value::String(_) => Ok(weak),
+			weak => Err(E::type_mismatch("object or string", weak)),
+		}
+	}
+
+	fn strong_into_weak(&self, strong: Self::Strong) -> Self::Weak {
+		strong
+	}
+
+	fn try_weak_into_strong(&self, weak: Self::Weak) -> Result<Self::Strong, Self::Error> {
+		match weak {
+			Value::Object(_) | Value::String(_) => Ok(weak),
+			weak => Err(E::type_mismatch("object or string", &weak)),
+		}
+	}
+}
+
+impl<E: Error> Descriptor for StringUnit<E> {
+	type Weak = Value;
+	type Strong = String;
+	type Error = E;
+
+	fn strong<'a>(&self, weak: &'a Self::Weak) -> Result<&'a Self::Strong, Self::Error> {
+		match weak {
+			Value::String(strong) => Ok(strong),
+			weak => Err(E::type_mismatch("string", weak)),
+		}
+	}
+
+	fn strong_mut<'a>(
+		&self,
+		weak: &'a mut Self::Weak,
+	) -> Result<&'a mut Self::Strong, Self::Error> {
+		match weak {
+			Value::String(strong) => Ok(strong),
+			weak => Err(E::type_mismatch("string", weak)),
+		}
+	}
+
+	fn strong_into_weak(&self, strong: Self::Strong) -> Self::Weak {
+		Value::String(strong)
+	}
+
+	fn try_weak_into_strong(&self, weak: Self::Weak) -> Result<Self::Strong, Self::Error> {
+		match weak {
+			Value::String(strong) => Ok(strong),
+			weak => Err(E::type_mismatch("string", &weak)),
+		}
+	}
+}
+
+impl<'a, 'n, E: Error> VariantFilter<'a, Map<String, Value>, E, &'n str> for InternallyTagged<E> {
+	type CommonRef = &'a Map<String, Value>;
+	type CommonMut = FieldsMut<'a>;
+
+	fn common(
+		&self,
+		strong: &'a Map<String, Value>,
+		name: &'n str,
+	) -> Result<Option<Self::CommonRef>, E> {
+		Ok(if tag::<E>(strong, self.tag)? == name {
+			Some(strong)
+		} else {
+			None
+		})
+	}
+
+	fn common_mut(
+		&self,
+		strong: &'a mut Map<String, Value>,
+		name: &'n str,
+	) -> Result<Result<Self::CommonMut, &'a mut Map<String, Value>>, E> {
+		Ok(if tag::<E>(strong, self.tag)? == name {
+			Ok(FieldsMut::split(strong))
+		} else {
+			Err(strong)
+		})
+	}
+
+	fn classify(
+		&self,
+		strong: &Map<String, Value>,
+		names: &[&'n str],
+	) -> Result<Classification, E> {
+		let tag = tag::<E>(strong, self.tag)?;
+		Ok(names
+			.iter()
+			.position(|name| *name == tag)
+			.map_or(Classification::Unrecognized, Classification::Variant))
+	}
+}
+
+impl<'a, 'n, E: Error, T: View<Value>>
+	VariantFieldAccessRef<'a, &'a Map<String, Value>, E, T, &'n str> for InternallyTagged<E>
+{
+	fn get(&self, common: &&'a Map<String, Value>, name: &'n str) -> Result<&'a T, E> {
+		common
+			.get(name)
+			.map(T::from_ref)
+			.ok_or_else(|| E::missing_field(&name))
+	}
+}
+
+impl<'a, 'n, E: Error, T: View<Value>> VariantFieldAccessMut<'a, FieldsMut<'a>, E, T, &'n str>
+	for InternallyTagged<E>
+{
+	fn get_mut(&self, common: &mut FieldsMut<'a>, name: &'n str) -> Result<&'a mut T, E> {
+		common.field(name).map(T::from_mut)
+	}
+}
+
+impl<'a, 'n, E: Error> VariantFilter<'a, Map<String, Value>, E, &'n str> for AdjacentlyTagged<E> {
+	type CommonRef = Option<&'a Value>;
+	type CommonMut = FieldsMut<'a>;
+
+	fn common(
+		&self,
+		strong: &'a Map<String, Value>,
+		name: &'n str,
+	) -> Result<Option<Self::CommonRef>, E> {
+		Ok(if tag::<E>(strong, self.tag)? == name {
+			Some(strong.get(self.content))
+		} else {
+			None
+		})
+	}
+
+	fn common_mut(
+		&self,
+		strong: &'a mut Map<String, Value>,
+		name: &'n str,
+	) -> Result<Result<Self::CommonMut, &'a mut Map<String, Value>>, E> {
+		Ok(if tag::<E>(strong, self.tag)? == name {
+			Ok(FieldsMut::whole(strong.get_mut(self.content)))
+		} else {
+			Err(strong)
+		})
+	}
+
+	fn classify(
+		&self,
+		strong: &Map<String, Value>,
+		names: &[&'n str],
+	) -> Result<Classification, E> {
+		let tag = tag::<E>(strong, self.tag)?;
+		Ok(names
+			.iter()
+			.position(|name| *name == tag)
+			.map_or(Classification::Unrecognized, Classification::Variant))
+	}
+}
+
+impl<'a, 'n, E: Error> VariantFilter<'a, Value, E, &'n str> for ExternallyTagged<E> {
+	type CommonRef = Option<&'a Value>;
+	type CommonMut = FieldsMut<'a>;
+
+	fn common(&self, strong: &'a Value, name: &'n str) -> Result<Option<Self::CommonRef>, E> {
+		match strong {
+			Value::String(unit) => Ok(Some(None).filter(|_| unit == name)),
+			Value::Object(object) if object.len() == 1 => Ok(object.get(name).map(Some)),
+			strong => Err(E::type_mismatch("single-field object or string", strong)),
+		}
+	}
+
+	fn common_mut(
+		&self,
+		strong: &'a mut Value,
+		name: &'n str,
+	) -> Result<Result<Self::CommonMut, &'a mut Value>, E> {
+		if self.common(strong, name)?.is_none() {
+			return Ok(Err(strong));
+		}
+		Ok(Ok(FieldsMut::whole(match strong {
+			Value::Object(object) => object.get_mut(name),
+			_ => None,
+		})))
+	}
+
+	fn classify(&self, strong: &Value, names: &[&'n str]) -> Result<Classification, E> {
+		let tag = match strong {
+			Value::String(unit) => unit,
+			Value::Object(object) if object.len() == 1 => {
+				object.keys().next().expect("unreachable")
+			}
+			strong => return Err(E::type_mismatch("single-field object or string", strong)),
+		};
+		Ok(names
+			.iter()
+			.position(|name| name == tag)
+			.map_or(Classification::Unrecognized, Classification::Variant))
+	}
+}
+
+macro_rules! content_field_access {
+	($($name:ident),*$(,)?) => {$(
+		impl<'a, 'n, E: Error, T: View<Value>> VariantFieldAccessRef<'a, Option<&'a Value>, E, T, &'n str>
+			for $name<E>
+		{
+			#[allow(clippy::ref_option_ref)] // Required by the trait.
+			fn get(&self, common: &Option<&'a Value>, name: &'n str) -> Result<&'a T, E> {
+				match common {
+					Some(Value::Object(content)) => content
+						.get(name)
+						.map(T::from_ref)
+						.ok_or_else(|| E::missing_field(&name)),
+					Some(content) => Err(E::type_mismatch("object", content)),
+					None => Err(E::missing_field(&name)),
+				}
+			}
+		}
+
+		impl<'a, E: Error, T: View<Value>> VariantFieldAccessRef<'a, Option<&'a Value>, E, T, ()>
+			for $name<E>
+		{
+			#[allow(clippy::ref_option_ref)] // Required by the trait.
+			fn get(&self, common: &Option<&'a Value>, (): ()) -> Result<&'a T, E> {
+				common.map(T::from_ref).ok_or_else(|| E::missing_field(&()))
+			}
+		}
+
+		impl<'a, 'n, E: Error, T: View<Value>> VariantFieldAccessMut<'a, FieldsMut<'a>, E, T, &'n str>
+			for $name<E>
+		{
+			fn get_mut(&self, common: &mut FieldsMut<'a>, name: &'n str) -> Result<&'a mut T, E> {
+				common.field(name).map(T::from_mut)
+			}
+		}
+
+		impl<'a, E: Error, T: View<Value>> VariantFieldAccessMut<'a, FieldsMut<'a>, E, T, ()>
+			for $name<E>
+		{
+			fn get_mut(&self, common: &mut FieldsMut<'a>, (): ()) -> Result<&'a mut T, E> {
+				common.content().map(T::from_mut)
+			}
+		}
+	)*};
+}
+content_field_access!(AdjacentlyTagged, ExternallyTagged);
+
+impl<'a, 'n, E: Error> VariantFilter<'a, String, E, &'n str> for StringUnit<E> {
+	type CommonRef = ();
+	type CommonMut = ();
+
+	fn common(&self, strong: &'a String, name: &'n str) -> Result<Option<Self::CommonRef>, E> {
+		Ok(Some(()).filter(|()| strong == name))
+	}
+
+	fn common_mut(
+		&self,
+		strong: &'a mut String,
+		name: &'n str,
+	) -> Result<Result<Self::CommonMut, &'a mut String>, E> {
+		Ok(if strong == name { Ok(()) } else { Err(strong) })
+	}
+
+	fn classify(&self, strong: &String, names: &[&'n str]) -> Result<Classification, E> {
+		Ok(names
+			.iter()
+			.position(|name| name == strong)
+			.map_or(Classification::Unrecognized, Classification::Variant))
+	}
+}
+
+impl<'n, E: Error> UnitVariantAccess<String, E, &'n str> for StringUnit<E> {
+	fn set(&self, strong: &mut String, name: &'n str) -> Result<(), E> {
+		strong.clear();
+		strong.push_str(name);
+		Ok(())
+	}
+}
+
+impl<E: Error> Descriptor for IntegerUnit<E> {
+	type Weak = Value;
+	type Strong = JsonNumber;
+	type Error = E;
+
+	fn strong<'a>(&self, weak: &'a Self::Weak) -> Result<&'a Self::Strong, Self::Error> {
+		match weak {
+			Value::Number(strong) => Ok(strong),
+			weak => Err(E::type_mismatch("number", weak)),
+		}
+	}
+
+	fn strong_mut<'a>(
+		&self,
+		weak: &'a mut Self::Weak,
+	) -> Result<&'a mut Self::Strong, Self::Error> {
+		match weak {
+			Value::Number(strong) => Ok(strong),
+			weak => Err(E::type_mismatch("number", weak)),
+		}
+	}
+
+	fn strong_into_weak(&self, strong: Self::Strong) -> Self::Weak {
+		Value::Number(strong)
+	}
+
+	fn try_weak_into_strong(&self, weak: Self::Weak) -> Result<Self::Strong, Self::Error> {
+		match weak {
+			Value::Number(strong) => Ok(strong),
+			weak => Err(E::type_mismatch("number", &weak)),
+		}
+	}
+}
+
+impl<'a, E: Error> VariantFilter<'a, JsonNumber, E, i64> for IntegerUnit<E> {
+	type CommonRef = ();
+	type CommonMut = ();
+
+	fn common(&self, strong: &'a JsonNumber, name: i64) -> Result<Option<Self::CommonRef>, E> {
+		Ok(Some(()).filter(|()| strong.as_i64() == Some(name)))
+	}
+
+	fn common_mut(
+		&self,
+		strong: &'a mut JsonNumber,
+		name: i64,
+	) -> Result<Result<Self::CommonMut, &'a mut JsonNumber>, E> {
+		Ok(if strong.as_i64() == Some(name) {
+			Ok(())
+		} else {
+			Err(strong)
+		})
+	}
+
+	fn classify(&self, strong: &JsonNumber, names: &[i64]) -> Result<Classification, E> {
+		Ok(strong
+			.as_i64()
+			.and_then(|strong| names.iter().position(|name| *name == strong))
+			.map_or(Classification::Unrecognized, Classification::Variant))
+	}
+}
+
+impl<E: Error> UnitVariantAccess<JsonNumber, E, i64> for IntegerUnit<E> {
+	fn set(&self, strong: &mut JsonNumber, name: i64) -> Result<(), E> {
+		*strong = name.into();
+		Ok(())
+	}
+}
+
+/// Data that can be borrowed from a JSON [`Value`] iff the value has the right shape.
+///
+/// Any [`View<Value>`] matches all values.
+pub trait Shape {
+	/// Borrows `value` as `Self`, iff it has the right shape.
+	fn from_value(value: &Value) -> Option<&Self>;
+
+	/// Mutably borrows `value` as `Self`, iff it has the right shape.
+	fn from_value_mut(value: &mut Value) -> Option<&mut Self>;
+
+	/// Takes `value` as `Self`, iff it has the right shape.
+	fn try_from_value(value: Value) -> Option<Self>
+	where
+		Self: Sized;
+
+	/// Converts `self` back into a JSON value.
+	fn into_value(self) -> Value
+	where
+		Self: Sized;
+}
+
+impl<T: View<Value>> Shape for T {
+	fn from_value(value: &Value) -> Option<&Self> {
+		Some(T::from_ref(value))
+	}
+
+	fn from_value_mut(value: &mut Value) -> Option<&mut Self> {
+		Some(T::from_mut(value))
+	}
+
+	fn try_from_value(value: Value) -> Option<Self> {
+		Some(<T as View<Value>>::from(value))
+	}
+
+	fn into_value(self) -> Value {
+		self.into_inner()
+	}
+}
+
+macro_rules! shapes {
+	($($ty:ty => $variant:ident),*$(,)?) => {$(
+		impl Shape for $ty {
+			fn from_value(value: &Value) -> Option<&Self> {
+				match value {
+					Value::$variant(value) => Some(value),
+					_ => None,
+				}
+			}
+
+			fn from_value_mut(value: &mut Value) -> Option<&mut Self> {
+				match value {
+					Value::$variant(value) => Some(value),
+					_ => None,
+				}
+			}
+
+			fn try_from_value(value: Value) -> Option<Self> {
+				match value {
+					Value::$variant(value) => Some(value),
+					_ => None,
+				}
+			}
+
+			fn into_value(self) -> Value {
+				Value::$variant(self)
+			}
+		}
+	)*};
+}
+shapes! {
+	bool => Bool,
+	JsonNumber => Number,
+	String => String,
+	Vec<Value> => Array,
+	Map<String, Value> => Object,
+}
+
+fn shape<T: Shape, E: Error>(value: &Value) -> Result<&T, E> {
+	T::from_value(value).ok_or_else(|| E::type_mismatch(type_name::<T>(), value))
+}
+
+fn shape_mut<T: Shape, E: Error>(value: &mut Value) -> Result<&mut T, E> {
+	if T::from_value(value).is_none() {
+		return Err(E::type_mismatch(type_name::<T>(), value));
+	}
+	Ok(T::from_value_mut(value).expect("checked above"))
+}
+
+/// Describes an enum stored without a tag, like `3` or `{ "x": 1, "y": 2 }`,
+/// whose variant is recognised by the [`Shape`] of its fields.
+///
+/// Variants are tried in order, and the first one whose fields all resolve is used.
+/// In [`strict`](`Untagged::strict`) mode, more than one such variant is an [`Error::ambiguous_variant`] instead.
+///
+/// Variant names are ignored. Fields are named by `&str` for fields of an object, or by `()` for the value itself.
+///
+/// Since its weak and strong types are the same, use it with `no_weak_conversions`.
+pub struct Untagged<E> {
+	strict: bool,
+	_phantom: PhantomData<fn() -> E>,
+}
+impl<E> Untagged<E> {
+	/// Creates a new instance of this descriptor, which uses the first matching variant.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			strict: false,
+			_phantom: PhantomData,
+		}
+	}
+
+	/// Creates a new instance of this descriptor, which requires exactly one variant to match.
+	#[must_use]
+	pub const fn strict() -> Self {
+		Self {
+			strict: true,
+			_phantom: PhantomData,
+		}
+	}
+}
+
+impl<E> Default for Untagged<E> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Describes a union stored as any JSON value, whose fields are accessible iff the value has their [`Shape`]:
+///
+/// ```
+/// use faible::{faible, json};
+/// use serde_json::{json, Number, Value};
+/// # pub struct Error;
+/// # impl faible::Error for Error {
+/// #     fn no_variant_recognized() -> Self { Self }
+/// # }
+///
+/// #[faible(json::Union::<Error>::new(), weak = Value, strong = Value, names = "verbatim", no_weak_conversions)]
+/// pub union IdOrName {
+///     pub id: Number,
+///     pub name: String,
+/// }
+///
+/// let mut user = IdOrName::from(json!(7));
+/// assert_eq!(user.id().ok(), Some(Some(&Number::from(7))));
+/// assert_eq!(user.name().ok(), Some(None));
+///
+/// user.set_name("faible".to_string()).ok();
+/// assert_eq!(user.0, json!("faible"));
+/// ```
+///
+/// Field names are ignored, and assigning a field replaces the whole value.
+///
+/// Since its weak and strong types are the same, use it with `no_weak_conversions`.
+pub struct Union<E>(PhantomData<fn() -> E>);
+impl<E> Union<E> {
+	/// Creates a new instance of this descriptor.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<E> Default for Union<E> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+macro_rules! value_descriptor {
+	($($name:ident),*$(,)?) => {$(
+		impl<E: Error> Descriptor for $name<E> {
+			type Weak = Value;
+			type Strong = Value;
+			type Error = E;
+
+			fn strong<'a>(&self, weak: &'a Self::Weak) -> Result<&'a Self::Strong, Self::Error> {
+				Ok(weak)
+			}
+
+			fn strong_mut<'a>(
+				&self,
+				weak: &'a mut Self::Weak,
+			) -> Result<&'a mut Self::Strong, Self::Error> {
+				Ok(weak)
+			}
+
+			fn strong_into_weak(&self, strong: Self::Strong) -> Self::Weak {
+				strong
+			}
+
+			fn try_weak_into_strong(&self, weak: Self::Weak) -> Result<Self::Strong, Self::Error> {
+				Ok(weak)
+			}
+		}
+	)*};
+}
+value_descriptor!(Untagged, Union);
+
+impl<E: Error, T: Shape, N> UnionFieldAccess<Value, E, T, N> for Union<E> {
+	fn get<'a>(&self, strong: &'a Value, _name: N) -> Result<Option<&'a T>, E> {
+		Ok(T::from_value(strong))
+	}
+
+	fn get_mut<'a>(&self, strong: &'a mut Value, _name: N) -> Result<Option<&'a mut T>, E> {
+		Ok(T::from_value_mut(strong))
+	}
+
+	fn set(&self, strong: &mut Value, _name: N, value: T) -> Result<(), E>
+	where
+		T: Sized,
+	{
+		*strong = value.into_value();
+		Ok(())
+	}
+
+	fn insert<'a>(
+		&self,
+		strong: &'a mut Value,
+		_name: N,
+		value: T,
+	) -> Result<(&'a mut T, Option<T>), E>
+	where
+		T: Sized,
+	{
+		let prev = mem::replace(strong, value.into_value());
+		Ok((
+			T::from_value_mut(strong).expect("just assigned"),
+			T::try_from_value(prev),
+		))
+	}
+}
+
+impl<'a, E: Error, N> VariantFilter<'a, Value, E, N> for Untagged<E> {
+	type CommonRef = &'a Value;
+	type CommonMut = FieldsMut<'a>;
+
+	fn common(&self, strong: &'a Value, _name: N) -> Result<Option<Self::CommonRef>, E> {
+		Ok(Some(strong))
+	}
+
+	fn common_mut(
+		&self,
+		strong: &'a mut Value,
+		_name: N,
+	) -> Result<Result<Self::CommonMut, &'a mut Value>, E> {
+		Ok(Ok(FieldsMut::whole(Some(strong))))
+	}
+
+	fn probing(&self, _strong: &Value, _name: N) -> Probing {
+		if self.strict {
+			Probing::Strict
+		} else {
+			Probing::First
+		}
+	}
+}
+
+impl<'a, 'n, E: Error, T: Shape> VariantFieldAccessRef<'a, &'a Value, E, T, &'n str>
+	for Untagged<E>
+{
+	fn get(&self, common: &&'a Value, name: &'n str) -> Result<&'a T, E> {
+		match common {
+			Value::Object(object) => object
+				.get(name)
+				.ok_or_else(|| E::missing_field(&name))
+				.and_then(shape),
+			common => Err(E::type_mismatch("object", common)),
+		}
+	}
+}
+
+impl<'a, E: Error, T: Shape> VariantFieldAccessRef<'a, &'a Value, E, T, ()> for Untagged<E> {
+	fn get(&self, common: &&'a Value, (): ()) -> Result<&'a T, E> {
+		shape(common)
+	}
+}
+
+impl<'a, 'n, E: Error, T: Shape> VariantFieldAccessMut<'a, FieldsMut<'a>, E, T, &'n str>
+	for Untagged<E>
+{
+	fn get_mut(&self, common: &mut FieldsMut<'a>, name: &'n str) -> Result<&'a mut T, E> {
+		common.field(name).and_then(shape_mut)
+	}
+}
+
+impl<'a, E: Error, T: Shape> VariantFieldAccessMut<'a, FieldsMut<'a>, E, T, ()> for Untagged<E> {
+	fn get_mut(&self, common: &mut FieldsMut<'a>, (): ()) -> Result<&'a mut T, E> {
+		common.content().and_then(shape_mut)
+	}
+}
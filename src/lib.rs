@@ -7,26 +7,150 @@
 #![allow(clippy::semicolon_if_nothing_returned)]
 #![no_std]
 
-use core::mem::{ManuallyDrop, MaybeUninit};
+use core::{
+	fmt::Debug,
+	mem::{self, ManuallyDrop},
+};
+
+#[cfg(any(feature = "rmpv", feature = "serde_json"))]
+extern crate alloc;
 
 #[cfg(doctest)]
 #[doc = include_str!("../README.md")]
 mod readme {}
 
+/// Implements strongly typed access to a struct, enum or union's weakly typed value through a [`Descriptor`].
+///
+/// # `weak` and `strong` types
+///
+/// `#[faible(…, weak = <type>, strong = <type>)]` spells out the descriptor's [`Descriptor::Weak`] and [`Descriptor::Strong`] types,
+/// which are otherwise used through projections.
+///
+/// These projections are fine for descriptors defined in the same crate as the `#[faible]` type.
+/// Rust's coherence check doesn't normalise projections onto descriptors from other crates, though (including the ones bundled here),
+/// so the generated conversions and [`View`] implementation then conflict with blanket implementations unless both types are given.
 pub use faible_proc_macro_definitions::faible;
 
-/// Allows specific errors to be created in generated code.
+pub mod binary;
+pub mod bits;
+#[cfg(feature = "bson")]
+pub mod bson;
+#[cfg(feature = "js")]
+pub mod js;
+#[cfg(feature = "serde_json")]
+pub mod json;
+#[cfg(feature = "rmpv")]
+pub mod msgpack;
+
+/// Allows specific errors to be created in generated code and bundled descriptors.
 pub trait Error {
 	/// Raised iff no matching enum variant could be determined.
+	#[must_use]
 	fn no_variant_recognized() -> Self;
+
+	/// Raised iff more than one enum variant matched, but the [`VariantFilter`] requires a unique one.
+	///
+	/// Defaults to [`Error::no_variant_recognized`].
+	#[must_use]
+	fn ambiguous_variant() -> Self
+	where
+		Self: Sized,
+	{
+		Self::no_variant_recognized()
+	}
+
+	/// Raised iff a value isn't of the `expected` type.
+	///
+	/// `expected` is a short description like `"map"` or `"ext"`.
+	///
+	/// Defaults to [`Error::no_variant_recognized`].
+	#[must_use]
+	fn type_mismatch(expected: &str, found: &dyn Debug) -> Self
+	where
+		Self: Sized,
+	{
+		let _ = (expected, found);
+		Self::no_variant_recognized()
+	}
+
+	/// Raised iff the field `name` doesn't exist.
+	///
+	/// Defaults to [`Error::no_variant_recognized`].
+	#[must_use]
+	fn missing_field(name: &dyn Debug) -> Self
+	where
+		Self: Sized,
+	{
+		let _ = name;
+		Self::no_variant_recognized()
+	}
+
+	/// Raised iff the field `name` exists, but can't be assigned, for example because it's read-only.
+	///
+	/// Defaults to [`Error::type_mismatch`].
+	#[must_use]
+	fn read_only_field(name: &dyn Debug) -> Self
+	where
+		Self: Sized,
+	{
+		Self::type_mismatch("writable field", name)
+	}
+
+	/// Raised iff a value is of the right kind, but can't be represented as the `expected` type.
+	///
+	/// `expected` is a short description like `"u8"` or `"finite number"`.
+	///
+	/// Defaults to [`Error::type_mismatch`].
+	#[must_use]
+	fn out_of_range(expected: &str, found: &dyn Debug) -> Self
+	where
+		Self: Sized,
+	{
+		Self::type_mismatch(expected, found)
+	}
+
+	/// Raised iff a versioned value's schema version is `found`, which can't be migrated to the current one.
+	///
+	/// Defaults to [`Error::type_mismatch`].
+	#[must_use]
+	fn unknown_version(found: &dyn Debug) -> Self
+	where
+		Self: Sized,
+	{
+		Self::type_mismatch("known version", found)
+	}
+
+	/// Raised iff a versioned value's schema version is `found`, which must be migrated to the current one before it's read.
+	///
+	/// Defaults to [`Error::type_mismatch`].
+	#[must_use]
+	fn outdated_version(found: &dyn Debug) -> Self
+	where
+		Self: Sized,
+	{
+		Self::type_mismatch("current version", found)
+	}
 }
 
+/// Implemented by `#[faible]` types, which wrap a [`Descriptor::Weak`] value.
 pub trait Faible {
+	/// The [`Descriptor`] used to interpret the wrapped value.
 	type Descriptor: Descriptor;
 
+	/// Borrows the wrapped value as [`Descriptor::Strong`].
+	///
+	/// # Errors
+	///
+	/// Iff the wrapped value isn't valid for [`Self::Descriptor`].
 	fn as_strong(
 		&self,
 	) -> Result<&<Self::Descriptor as Descriptor>::Strong, <Self::Descriptor as Descriptor>::Error>;
+
+	/// Mutably borrows the wrapped value as [`Descriptor::Strong`].
+	///
+	/// # Errors
+	///
+	/// Iff the wrapped value isn't valid for [`Self::Descriptor`].
 	fn as_strong_mut(
 		&mut self,
 	) -> Result<
@@ -35,60 +159,78 @@ pub trait Faible {
 	>;
 }
 
+/// Describes how a weakly typed value is interpreted.
 pub trait Descriptor {
+	/// The weakly typed representation, which is stored by `#[faible]` types.
 	type Weak;
+	/// The validated representation that fields are accessed through.
 	type Strong;
+	/// The error type of fallible accesses.
 	type Error: crate::Error;
 
+	/// Borrows `weak` as [`Self::Strong`].
+	///
+	/// # Errors
+	///
+	/// Iff `weak` isn't valid for this descriptor.
 	fn strong<'a>(&self, weak: &'a Self::Weak) -> Result<&'a Self::Strong, Self::Error>;
+
+	/// Mutably borrows `weak` as [`Self::Strong`].
+	///
+	/// # Errors
+	///
+	/// Iff `weak` isn't valid for this descriptor.
 	fn strong_mut<'a>(&self, weak: &'a mut Self::Weak)
 		-> Result<&'a mut Self::Strong, Self::Error>;
+
+	/// Converts `strong` back into its weakly typed representation.
 	fn strong_into_weak(&self, strong: Self::Strong) -> Self::Weak;
+
+	/// Converts `weak` into [`Self::Strong`].
+	///
+	/// # Errors
+	///
+	/// Iff `weak` isn't valid for this descriptor.
 	fn try_weak_into_strong(&self, weak: Self::Weak) -> Result<Self::Strong, Self::Error>;
 }
 
+/// Reinterpretation of `T` as `Self`.
+///
 /// # Safety
 ///
 /// `Self` must be transmutation-compatible with `T`.
 /// `&Self` must be transmutation-compatible with `&T`.
 /// `&mut Self` must be transmutation-compatible with `&mut T`.
 pub unsafe trait View<T: ?Sized> {
-	#[allow(missing_docs)]
+	/// Reinterprets `value` as `Self`.
 	fn from(value: T) -> Self
 	where
 		Self: Sized,
 		T: Sized,
 	{
-		unsafe {
-			(&ManuallyDrop::new(value) as *const ManuallyDrop<T>)
-				.cast::<Self>()
-				.read()
-		}
+		unsafe { mem::transmute_copy::<ManuallyDrop<T>, Self>(&ManuallyDrop::new(value)) }
 	}
 
-	#[allow(missing_docs)]
+	/// Reinterprets `value` as `&Self`.
 	fn from_ref(value: &T) -> &Self {
-		unsafe { *(&value as *const &T).cast() }
+		unsafe { mem::transmute_copy::<&T, &Self>(&value) }
 	}
 
-	#[allow(missing_docs)]
+	/// Reinterprets `value` as `&mut Self`.
 	fn from_mut(value: &mut T) -> &mut Self {
-		unsafe { MaybeUninit::new(value).as_ptr().cast::<&mut Self>().read() }
+		unsafe { mem::transmute_copy::<&mut T, &mut Self>(&ManuallyDrop::new(value)) }
 	}
 
-	#[allow(missing_docs)]
+	/// Reinterprets `self` as `T`.
 	fn into_inner(self) -> T
 	where
 		Self: Sized,
 		T: Sized,
 	{
-		unsafe {
-			(&ManuallyDrop::new(self) as *const ManuallyDrop<Self>)
-				.cast::<T>()
-				.read()
-		}
+		unsafe { mem::transmute_copy::<ManuallyDrop<Self>, T>(&ManuallyDrop::new(self)) }
 	}
 
+	/// Reinterprets the result of an insertion into a `T` slot.
 	fn from_insertion((slot, prev): (&mut T, Option<T>)) -> (&mut Self, Option<Self>)
 	where
 		Self: Sized,
@@ -103,12 +245,36 @@ pub unsafe trait View<T: ?Sized> {
 /// This is the identity pun.
 unsafe impl<T: ?Sized> View<T> for T {}
 
+/// Access to named fields of `Strong`, used by `#[faible]` structs.
 pub trait FieldAccess<Strong: ?Sized, E, T: ?Sized, N> {
+	/// Borrows the field `name`.
+	///
+	/// # Errors
+	///
+	/// Iff the field can't be borrowed as `T`.
 	fn get<'a>(&self, strong: &'a Strong, name: N) -> Result<&'a T, E>;
+
+	/// Mutably borrows the field `name`.
+	///
+	/// # Errors
+	///
+	/// Iff the field can't be borrowed as `T`.
 	fn get_mut<'a>(&self, strong: &'a mut Strong, name: N) -> Result<&'a mut T, E>;
+
+	/// Assigns `value` to the field `name`.
+	///
+	/// # Errors
+	///
+	/// Iff the field can't be assigned.
 	fn set(&self, strong: &mut Strong, name: N, value: T) -> Result<(), E>
 	where
 		T: Sized;
+
+	/// Assigns `value` to the field `name`, returning the field and its previous value.
+	///
+	/// # Errors
+	///
+	/// Iff the field can't be assigned.
 	fn insert<'a>(
 		&self,
 		strong: &'a mut Strong,
@@ -150,12 +316,103 @@ pub trait FieldAccess<Strong: ?Sized, E, T: ?Sized, N> {
 // 	}
 // }
 
+/// By-value access to named fields of `Strong`, used by `#[faible(by_value)]` struct fields.
+///
+/// Unlike with [`FieldAccess`], fields don't have to exist as `T` inside `Strong`,
+/// so they can be converted or computed on each access.
+pub trait FieldValueAccess<Strong: ?Sized, E, T, N> {
+	/// Reads the field `name`.
+	///
+	/// # Errors
+	///
+	/// Iff the field can't be read as `T`.
+	fn get(&self, strong: &Strong, name: N) -> Result<T, E>;
+
+	/// Writes `value` to the field `name`.
+	///
+	/// # Errors
+	///
+	/// Iff the field can't be assigned.
+	fn set(&self, strong: &mut Strong, name: N, value: T) -> Result<(), E>;
+}
+
+/// Presence checks and removal of named fields of `Strong`, used by `#[faible(alias = …)]` struct fields.
+///
+/// Aliases are name expressions like `name = …`, so `alias = "snake_case"` names the field in `snake_case`
+/// and `alias = "_parent"` is used literally.
+///
+/// This is implemented by the struct's descriptor rather than the field's.
+pub trait FieldPresence<Strong: ?Sized, E, N> {
+	/// Checks whether the field `name` is present.
+	///
+	/// # Errors
+	///
+	/// Iff `strong` is invalid.
+	fn contains(&self, strong: &Strong, name: N) -> Result<bool, E>;
+
+	/// Removes the field `name`, if present.
+	///
+	/// # Errors
+	///
+	/// Iff the field can't be removed.
+	fn remove(&self, strong: &mut Strong, name: N) -> Result<(), E>;
+}
+
+/// Access to the schema version of `Strong`, used by `#[faible(version_field = …, version = …, migrate = …)]` structs.
+///
+/// Like [`FieldPresence`], this is implemented by the struct's descriptor.
+///
+/// Their generated `upgrade` method calls `migrate(version, &mut weak)` once per version step,
+/// counting an absent version as 0, and stores `version + 1` after each step that succeeds.
+/// Shared field accessors fail with [`Error::outdated_version`] until then, while exclusive ones upgrade first.
+pub trait VersionAccess<Strong: ?Sized, E, N> {
+	/// Reads the version stored in the field `name`, or [`None`] iff it's absent.
+	///
+	/// # Errors
+	///
+	/// Iff the field isn't a version number.
+	fn version(&self, strong: &Strong, name: N) -> Result<Option<u64>, E>;
+
+	/// Stores `version` in the field `name`.
+	///
+	/// # Errors
+	///
+	/// Iff the field can't be assigned.
+	fn set_version(&self, strong: &mut Strong, name: N, version: u64) -> Result<(), E>;
+}
+
+/// Access to named fields of `Strong`, used by `#[faible]` unions.
+///
+/// Unlike with [`FieldAccess`], fields may be absent without this being an error.
 pub trait UnionFieldAccess<Strong: ?Sized, E, T: ?Sized, N> {
+	/// Borrows the field `name`, if it's currently accessible.
+	///
+	/// # Errors
+	///
+	/// Iff `strong` is invalid.
 	fn get<'a>(&self, strong: &'a Strong, name: N) -> Result<Option<&'a T>, E>;
+
+	/// Mutably borrows the field `name`, if it's currently accessible.
+	///
+	/// # Errors
+	///
+	/// Iff `strong` is invalid.
 	fn get_mut<'a>(&self, strong: &'a mut Strong, name: N) -> Result<Option<&'a mut T>, E>;
+
+	/// Assigns `value` to the field `name`, making it accessible.
+	///
+	/// # Errors
+	///
+	/// Iff the field can't be assigned.
 	fn set(&self, strong: &mut Strong, name: N, value: T) -> Result<(), E>
 	where
 		T: Sized;
+
+	/// Assigns `value` to the field `name`, returning the field and its previous value.
+	///
+	/// # Errors
+	///
+	/// Iff the field can't be assigned.
 	fn insert<'a>(
 		&self,
 		strong: &'a mut Strong,
@@ -197,14 +454,73 @@ pub trait UnionFieldAccess<Strong: ?Sized, E, T: ?Sized, N> {
 // 	}
 // }
 
+/// How `#[faible]` enums treat recognised variants whose fields don't all resolve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Probing {
+	/// The variant is used as recognised, and field errors are returned.
+	Off,
+	/// The variant is skipped, and the first variant whose fields all resolve is used.
+	First,
+	/// Like [`Probing::First`], but fails with [`Error::ambiguous_variant`] iff more than one variant resolves.
+	Strict,
+}
+
+/// The result of [`VariantFilter::classify`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Classification {
+	/// The value is the variant at this index of `names`, subject to confirmation through [`VariantFilter::common`].
+	Variant(usize),
+	/// The value was read, but none of `names` matches it, so only an `#[faible(other)]` variant can match.
+	Unrecognized,
+	/// The value can't be classified in one step, so variants are checked one by one.
+	Unknown,
+}
+
+/// Recognises the variants of `#[faible]` enums.
 pub trait VariantFilter<'a, Strong: ?Sized, E, N> {
+	/// Shared access to a recognised variant's data, passed to [`VariantFieldAccessRef`].
 	type CommonRef: 'a;
+	/// Exclusive access to a recognised variant's data, passed to [`VariantFieldAccessMut`].
 	type CommonMut: 'a;
 
+	/// Returns the variant `name`'s data iff `strong` is that variant.
+	///
+	/// # Errors
+	///
+	/// Iff `strong` is invalid.
 	fn common(&self, strong: &'a Strong, name: N) -> Result<Option<Self::CommonRef>, E>;
 
-	fn check_mut(&self, strong: &mut Strong, name: N) -> Result<bool, E>;
-	fn common_mut(&self, strong: &'a mut Strong, name: N) -> Result<Self::CommonMut, E>;
+	/// Returns the variant `name`'s data iff `strong` is that variant, or hands `strong` back otherwise.
+	///
+	/// # Errors
+	///
+	/// Iff `strong` is invalid.
+	#[allow(clippy::type_complexity)]
+	fn common_mut(
+		&self,
+		strong: &'a mut Strong,
+		name: N,
+	) -> Result<Result<Self::CommonMut, &'a mut Strong>, E>;
+
+	/// Determines the index of `strong`'s variant in `names` in one step, like with a single tag lookup.
+	///
+	/// Only used if all variant names are literals of the same type.
+	///
+	/// Defaults to [`Classification::Unknown`].
+	///
+	/// # Errors
+	///
+	/// Iff `strong` is invalid.
+	fn classify(&self, _strong: &Strong, _names: &[N]) -> Result<Classification, E> {
+		Ok(Classification::Unknown)
+	}
+
+	/// Returns how the variant `name` is treated if it's recognised in `strong`, but its fields don't all resolve.
+	///
+	/// Defaults to [`Probing::Off`].
+	fn probing(&self, _strong: &Strong, _name: N) -> Probing {
+		Probing::Off
+	}
 }
 // impl<Strong: ?Sized, E, N, T> VariantFilter<Strong, E, N> for &T
 // where
@@ -214,10 +530,33 @@ pub trait VariantFilter<'a, Strong: ?Sized, E, N> {
 // 		T::predicate(self, strong, name)
 // 	}
 // }
+/// Shared access to the fields of a recognised `#[faible]` enum variant.
 pub trait VariantFieldAccessRef<'a, Common: ?Sized, E, T: ?Sized, N> {
+	/// Borrows the field `name`.
+	///
+	/// # Errors
+	///
+	/// Iff the field can't be borrowed as `T`.
 	fn get(&self, common: &Common, name: N) -> Result<&'a T, E>;
 }
+/// Exclusive access to the fields of a recognised `#[faible]` enum variant.
 pub trait VariantFieldAccessMut<'a, Common: ?Sized, E, T: ?Sized, N> {
+	/// Mutably borrows the field `name`.
+	///
+	/// # Errors
+	///
+	/// Iff the field can't be borrowed as `T`.
 	fn get_mut(&self, common: &mut Common, name: N) -> Result<&'a mut T, E>;
 }
+/// Writes unit variants into `Strong`.
+///
+/// Fieldless `#[faible]` enums generate a `set_variant` method through this when declared with `#[faible(…, set_variant)]`.
+pub trait UnitVariantAccess<Strong: ?Sized, E, N> {
+	/// Makes `strong` the unit variant `name`.
+	///
+	/// # Errors
+	///
+	/// Iff `name` can't be written to `strong`.
+	fn set(&self, strong: &mut Strong, name: N) -> Result<(), E>;
+}
 //TODO: Get owned, set and insert. Also to-owned conversions.
@@ -0,0 +1,378 @@
+//! Descriptors over [`rmpv::Value`], for MessagePack data.
+//!
+//! All descriptors here are generic over the [`Error`] type `E` of the `#[faible]` types using them,
+//! so it's usually necessary to specify it explicitly: `#[faible(msgpack::Map::<MyError>::new(), …)]`.
+//! The [`weak` and `strong` types](crate::faible#weak-and-strong-types) should be spelled out as well.
+
+use crate::{
+	Classification, Descriptor, Error, FieldAccess, VariantFieldAccessMut, VariantFieldAccessRef,
+	VariantFilter, View,
+};
+use alloc::vec::Vec;
+use core::{cmp::Ordering, fmt::Debug, marker::PhantomData, mem};
+use rmpv::{Integer, Value};
+
+/// Names of [`Map`] fields, which are compared against MessagePack map keys.
+///
+/// Implemented for string and integer names.
+pub trait Key: Debug {
+	/// Checks whether `key` is this name.
+	fn matches(&self, key: &Value) -> bool;
+
+	/// Creates the map key for this name, for insertions.
+	fn to_key(&self) -> Value;
+}
+
+impl Key for &str {
+	fn matches(&self, key: &Value) -> bool {
+		key.as_str() == Some(*self)
+	}
+
+	fn to_key(&self) -> Value {
+		(*self).into()
+	}
+}
+
+macro_rules! integer_keys {
+	($($ty:ty),*$(,)?) => {$(
+		impl Key for $ty {
+			fn matches(&self, key: &Value) -> bool {
+				matches!(key, Value::Integer(key) if *key == <Integer as From<$ty>>::from(*self))
+			}
+
+			fn to_key(&self) -> Value {
+				(*self).into()
+			}
+		}
+	)*};
+}
+integer_keys!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+/// Describes a MessagePack map with string and/or integer keys.
+///
+/// Field names are [`Key`]s. Setting an absent field appends it to the map.
+pub struct Map<E>(PhantomData<fn() -> E>);
+impl<E> Map<E> {
+	/// Creates a new instance of this descriptor.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<E> Default for Map<E> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<E: Error> Descriptor for Map<E> {
+	type Weak = Value;
+	type Strong = Vec<(Value, Value)>;
+	type Error = E;
+
+	fn strong<'a>(&self, weak: &'a Self::Weak) -> Result<&'a Self::Strong, Self::Error> {
+		match weak {
+			Value::Map(strong) => Ok(strong),
+			weak => Err(E::type_mismatch("map", weak)),
+		}
+	}
+
+	fn strong_mut<'a>(
+		&self,
+		weak: &'a mut Self::Weak,
+	) -> Result<&'a mut Self::Strong, Self::Error> {
+		match weak {
+			Value::Map(strong) => Ok(strong),
+			weak => Err(E::type_mismatch("map", weak)),
+		}
+	}
+
+	fn strong_into_weak(&self, strong: Self::Strong) -> Self::Weak {
+		Value::Map(strong)
+	}
+
+	fn try_weak_into_strong(&self, weak: Self::Weak) -> Result<Self::Strong, Self::Error> {
+		match weak {
+			Value::Map(strong) => Ok(strong),
+			weak => Err(E::type_mismatch("map", &weak)),
+		}
+	}
+}
+
+impl<E: Error, T: View<Value>, N: Key> FieldAccess<Vec<(Value, Value)>, E, T, N> for Map<E> {
+	fn get<'a>(&self, strong: &'a Vec<(Value, Value)>, name: N) -> Result<&'a T, E> {
+		strong
+			.iter()
+			.find(|(key, _)| name.matches(key))
+			.map(|(_, value)| T::from_ref(value))
+			.ok_or_else(|| E::missing_field(&name))
+	}
+
+	fn get_mut<'a>(&self, strong: &'a mut Vec<(Value, Value)>, name: N) -> Result<&'a mut T, E> {
+		strong
+			.iter_mut()
+			.find(|(key, _)| name.matches(key))
+			.map(|(_, value)| T::from_mut(value))
+			.ok_or_else(|| E::missing_field(&name))
+	}
+
+	fn set(&self, strong: &mut Vec<(Value, Value)>, name: N, value: T) -> Result<(), E>
+	where
+		T: Sized,
+	{
+		let value = value.into_inner();
+		match strong.iter_mut().find(|(key, _)| name.matches(key)) {
+			Some((_, slot)) => *slot = value,
+			None => strong.push((name.to_key(), value)),
+		}
+		Ok(())
+	}
+
+	fn insert<'a>(
+		&self,
+		strong: &'a mut Vec<(Value, Value)>,
+		name: N,
+		value: T,
+	) -> Result<(&'a mut T, Option<T>), E>
+	where
+		T: Sized,
+	{
+		let value = value.into_inner();
+		Ok(T::from_insertion(
+			if let Some(index) = strong.iter().position(|(key, _)| name.matches(key)) {
+				let slot = &mut strong[index].1;
+				let prev = mem::replace(slot, value);
+				(slot, Some(prev))
+			} else {
+				strong.push((name.to_key(), value));
+				(&mut strong.last_mut().expect("unreachable").1, None)
+			},
+		))
+	}
+}
+
+/// Describes a MessagePack array.
+///
+/// Field names are `usize` indices. Setting the field just past the end appends to the array.
+pub struct Array<E>(PhantomData<fn() -> E>);
+impl<E> Array<E> {
+	/// Creates a new instance of this descriptor.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<E> Default for Array<E> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<E: Error> Descriptor for Array<E> {
+	type Weak = Value;
+	type Strong = Vec<Value>;
+	type Error = E;
+
+	fn strong<'a>(&self, weak: &'a Self::Weak) -> Result<&'a Self::Strong, Self::Error> {
+		match weak {
+			Value::Array(strong) => Ok(strong),
+			weak => Err(E::type_mismatch("array", weak)),
+		}
+	}
+
+	fn strong_mut<'a>(
+		&self,
+		weak: &'a mut Self::Weak,
+	) -> Result<&'a mut Self::Strong, Self::Error> {
+		match weak {
+			Value::Array(strong) => Ok(strong),
+			weak => Err(E::type_mismatch("array", weak)),
+		}
+	}
+
+	fn strong_into_weak(&self, strong: Self::Strong) -> Self::Weak {
+		Value::Array(strong)
+	}
+
+	fn try_weak_into_strong(&self, weak: Self::Weak) -> Result<Self::Strong, Self::Error> {
+		match weak {
+			Value::Array(strong) => Ok(strong),
+			weak => Err(E::type_mismatch("array", &weak)),
+		}
+	}
+}
+
+impl<E: Error, T: View<Value>> FieldAccess<Vec<Value>, E, T, usize> for Array<E> {
+	fn get<'a>(&self, strong: &'a Vec<Value>, name: usize) -> Result<&'a T, E> {
+		strong
+			.get(name)
+			.map(T::from_ref)
+			.ok_or_else(|| E::missing_field(&name))
+	}
+
+	fn get_mut<'a>(&self, strong: &'a mut Vec<Value>, name: usize) -> Result<&'a mut T, E> {
+		strong
+			.get_mut(name)
+			.map(T::from_mut)
+			.ok_or_else(|| E::missing_field(&name))
+	}
+
+	fn set(&self, strong: &mut Vec<Value>, name: usize, value: T) -> Result<(), E>
+	where
+		T: Sized,
+	{
+		self.insert(strong, name, value).map(drop)
+	}
+
+	fn insert<'a>(
+		&self,
+		strong: &'a mut Vec<Value>,
+		name: usize,
+		value: T,
+	) -> Result<(&'a mut T, Option<T>), E>
+	where
+		T: Sized,
+	{
+		let value = value.into_inner();
+		Ok(T::from_insertion(match name.cmp(&strong.len()) {
+			Ordering::Less => {
+				let slot = &mut strong[name];
+				let prev = mem::replace(slot, value);
+				(slot, Some(prev))
+			}
+			Ordering::Equal => {
+				strong.push(value);
+				(strong.last_mut().expect("unreachable"), None)
+			}
+			Ordering::Greater => return Err(E::missing_field(&name)),
+		}))
+	}
+}
+
+/// Describes a MessagePack extension value, as enum whose variants are named by `i8` type codes.
+///
+/// Each variant has a single field named `()`, which is the extension's data as `Vec<u8>`:
+///
+/// ```
+/// use faible::{faible, msgpack};
+/// # pub struct Error;
+/// # impl faible::Error for Error {
+/// #     fn no_variant_recognized() -> Self { Self }
+/// # }
+///
+/// #[faible(
+///     msgpack::Ext::<Error>::new(),
+///     weak = rmpv::Value,
+///     strong = rmpv::Value,
+///     names = index,
+///     nested_names = (),
+///     no_weak_conversions,
+/// )]
+/// pub enum Extension {
+///     Position(Vec<u8>),
+///     Velocity(Vec<u8>),
+/// }
+/// ```
+pub struct Ext<E>(PhantomData<fn() -> E>);
+impl<E> Ext<E> {
+	/// Creates a new instance of this descriptor.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<E> Default for Ext<E> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<E: Error> Descriptor for Ext<E> {
+	type Weak = Value;
+	type Strong = Value;
+	type Error = E;
+
+	fn strong<'a>(&self, weak: &'a Self::Weak) -> Result<&'a Self::Strong, Self::Error> {
+		match weak {
+			Value::Ext(..) => Ok(weak),
+			weak => Err(E::type_mismatch("ext", weak)),
+		}
+	}
+
+	fn strong_mut<'a>(
+		&self,
+		weak: &'a mut Self::Weak,
+	) -> Result<&'a mut Self::Strong, Self::Error> {
+		match weak {
+			Value::Ext(..) => Ok(weak),
+			weak => Err(E::type_mismatch("ext", weak)),
+		}
+	}
+
+	fn strong_into_weak(&self, strong: Self::Strong) -> Self::Weak {
+		strong
+	}
+
+	fn try_weak_into_strong(&self, weak: Self::Weak) -> Result<Self::Strong, Self::Error> {
+		match weak {
+			Value::Ext(..) => Ok(weak),
+			weak => Err(E::type_mismatch("ext", &weak)),
+		}
+	}
+}
+
+impl<'a, E: Error> VariantFilter<'a, Value, E, i8> for Ext<E> {
+	type CommonRef = &'a Vec<u8>;
+	type CommonMut = Option<&'a mut Vec<u8>>;
+
+	fn common(&self, strong: &'a Value, name: i8) -> Result<Option<Self::CommonRef>, E> {
+		match strong {
+			Value::Ext(type_, data) => Ok(Some(data).filter(|_| *type_ == name)),
+			strong => Err(E::type_mismatch("ext", strong)),
+		}
+	}
+
+	fn common_mut(
+		&self,
+		strong: &'a mut Value,
+		name: i8,
+	) -> Result<Result<Self::CommonMut, &'a mut Value>, E> {
+		if self.common(strong, name)?.is_none() {
+			return Ok(Err(strong));
+		}
+		Ok(Ok(match strong {
+			Value::Ext(_, data) => Some(data),
+			_ => None,
+		}))
+	}
+
+	fn classify(&self, strong: &Value, names: &[i8]) -> Result<Classification, E> {
+		match strong {
+			Value::Ext(type_, _) => Ok(names
+				.iter()
+				.position(|name| name == type_)
+				.map_or(Classification::Unrecognized, Classification::Variant)),
+			strong => Err(E::type_mismatch("ext", strong)),
+		}
+	}
+}
+
+impl<'a, E: Error, T: View<Vec<u8>>> VariantFieldAccessRef<'a, &'a Vec<u8>, E, T, ()> for Ext<E> {
+	fn get(&self, common: &&'a Vec<u8>, (): ()) -> Result<&'a T, E> {
+		Ok(T::from_ref(common))
+	}
+}
+
+impl<'a, E: Error, T: View<Vec<u8>>> VariantFieldAccessMut<'a, Option<&'a mut Vec<u8>>, E, T, ()>
+	for Ext<E>
+{
+	fn get_mut(&self, common: &mut Option<&'a mut Vec<u8>>, (): ()) -> Result<&'a mut T, E> {
+		common
+			.take()
+			.map(T::from_mut)
+			.ok_or_else(|| E::missing_field(&()))
+	}
+}
@@ -0,0 +1,129 @@
+use faible::{
+	binary::{self, U16Le, U32Be},
+	faible, View,
+};
+use std::convert::TryInto;
+
+mod common;
+use common::Error;
+
+#[faible(
+	binary::Layout::<Error, 12>::new(),
+	weak = [u8; 12],
+	strong = [u8; 12],
+	names = index,
+	no_weak_conversions
+)]
+pub struct Header {
+	#[faible(_, name = 0x0)]
+	pub magic: [u8; 4],
+	#[faible(_, name = 0x4)]
+	pub version: U16Le,
+	#[faible(_, name = 0x6)]
+	pub flags: u8,
+	#[faible(_, name = 0x8)]
+	pub length: U32Be,
+	#[faible(_, name = 0xA)]
+	pub overhanging: U32Be,
+}
+
+#[faible(
+	binary::SliceLayout::<Error, &'a mut [u8]>::new(),
+	weak = &'a mut [u8],
+	strong = &'a mut [u8],
+	names = index,
+	no_weak_conversions
+)]
+pub struct Packet<'a> {
+	#[faible(_, name = 0x0)]
+	pub kind: u8,
+	#[faible(_, name = 0x1)]
+	pub length: U16Le,
+}
+
+#[faible(
+	binary::SliceLayout::<Error, &'a [u8]>::new(),
+	weak = &'a [u8],
+	strong = &'a [u8],
+	names = index,
+	no_weak_conversions
+)]
+pub struct PacketRef<'a> {
+	#[faible(_, name = 0x0)]
+	pub kind: u8,
+	#[faible(_, name = 0x1)]
+	pub length: U16Le,
+}
+
+const BYTES: [u8; 12] = *b"FAIB\x02\x00\x81\x00\x00\x00\x01\x00";
+
+#[test]
+fn read_fields() {
+	let header: Header = BYTES.into();
+	assert_eq!(header.magic().unwrap(), b"FAIB");
+	assert_eq!(header.version().unwrap().get(), 2);
+	assert_eq!(header.flags().unwrap(), &0x81);
+	assert_eq!(header.length().unwrap().get(), 0x100);
+}
+
+#[test]
+fn write_fields() {
+	let mut header: Header = BYTES.into();
+	header.set_flags(0x01).unwrap();
+	header.length_mut().unwrap().set(0xAABB_CCDD);
+
+	let (version, prev) = header.insert_version(U16Le::new(0x0304)).unwrap();
+	assert_eq!(version.get(), 0x0304);
+	assert_eq!(prev.map(U16Le::get), Some(2));
+
+	assert_eq!(header.0, *b"FAIB\x04\x03\x01\x00\xAA\xBB\xCC\xDD");
+}
+
+#[test]
+fn out_of_bounds() {
+	let mut header: Header = BYTES.into();
+	assert_eq!(
+		header.overhanging().unwrap_err(),
+		Error::MissingField("10".to_string())
+	);
+	assert_eq!(
+		header.set_overhanging(U32Be::new(0)).unwrap_err(),
+		Error::MissingField("10".to_string())
+	);
+	assert_eq!(header.0, BYTES);
+}
+
+#[test]
+fn in_place() {
+	let mut packet = [0; 16];
+	packet[..12].copy_from_slice(&BYTES);
+
+	let bytes: &mut [u8; 12] = (&mut packet[..12]).try_into().unwrap();
+	let header = Header::from_mut(bytes);
+	header.set_flags(0).unwrap();
+	assert_eq!(packet[6], 0);
+}
+
+#[test]
+fn slices() {
+	let mut buffer = [1, 0x34, 0x12, 0xFF];
+
+	let mut packet: Packet = (&mut buffer[..3]).into();
+	assert_eq!(packet.length().unwrap().get(), 0x1234);
+	packet.set_kind(2).unwrap();
+
+	let mut packet: Packet = (&mut buffer[2..]).into();
+	assert_eq!(
+		packet.length().unwrap_err(),
+		Error::MissingField("1".to_string())
+	);
+	packet.set_kind(0).unwrap();
+	assert_eq!(buffer, [2, 0x34, 0, 0xFF]);
+
+	let mut packet: PacketRef = (&buffer[..]).into();
+	assert_eq!(packet.kind().unwrap(), &2);
+	assert_eq!(
+		packet.set_kind(3).unwrap_err(),
+		Error::ReadOnlyField("0".to_string())
+	);
+}
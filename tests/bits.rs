@@ -0,0 +1,127 @@
+use faible::{bits, faible};
+use std::fmt::Debug;
+
+mod common;
+use common::Error;
+
+#[faible(
+	bits::Packed::<u16, Error>::new(),
+	weak = u16,
+	strong = u16,
+	names = index,
+	no_weak_conversions
+)]
+pub struct Control {
+	#[faible(by_value, name = 0..1)]
+	pub enabled: bool,
+	#[faible(by_value, name = 0..2)]
+	pub low: u8,
+	#[faible(by_value, name = 4..6)]
+	pub mode: Mode,
+	#[faible(by_value, name = 8..16)]
+	pub high: u8,
+	#[faible(by_value, name = 12..16)]
+	pub top: u8,
+	#[faible(by_value, name = 0..16)]
+	pub all: u16,
+	#[faible(by_value, name = 0..9)]
+	pub too_wide: u8,
+	#[faible(by_value, name = 12..17)]
+	pub out_of_range: bool,
+	#[faible(by_value, name = 3..3)]
+	pub empty: bool,
+}
+
+#[faible(
+	bits::Packed::<u64, Error>::new(),
+	weak = u64,
+	strong = u64,
+	names = index,
+	no_weak_conversions
+)]
+pub struct Wide {
+	#[faible(by_value, name = 63..64)]
+	pub last: bool,
+	#[faible(by_value, name = 64..65)]
+	pub beyond: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Mode {
+	Idle,
+	Run,
+	Halt,
+}
+impl bits::Field for Mode {
+	fn from_bits(bits: u64) -> Option<Self> {
+		match bits {
+			0 => Some(Self::Idle),
+			1 => Some(Self::Run),
+			2 => Some(Self::Halt),
+			_ => None,
+		}
+	}
+
+	fn into_bits(self) -> u64 {
+		self as u64
+	}
+}
+
+#[test]
+fn get_fields() {
+	let control = Control::from(0b1100_0000_0001_0001);
+	assert_eq!(control.enabled(), Ok(true));
+	assert_eq!(control.mode(), Ok(Mode::Run));
+	assert_eq!(control.high(), Ok(0b1100_0000));
+	assert_eq!(control.all(), Ok(0b1100_0000_0001_0001));
+}
+
+#[test]
+fn set_fields() {
+	let mut control = Control::from(0b1111_1111_0000_0000);
+	control.set_enabled(true).unwrap();
+	control.set_mode(Mode::Halt).unwrap();
+	control.set_top(0b0101).unwrap();
+	assert_eq!(control.0, 0b0101_1111_0010_0001);
+}
+
+#[test]
+fn invalid_fields() {
+	let mut control = Control::from(0b0001_0011_0000);
+	assert_eq!(
+		control.mode(),
+		Err(Error::TypeMismatch("bits::Mode".to_string()))
+	);
+	assert_eq!(
+		control.too_wide(),
+		Err(Error::TypeMismatch("u8".to_string()))
+	);
+	assert_eq!(
+		control.out_of_range(),
+		Err(Error::MissingField("12..17".to_string()))
+	);
+	assert_eq!(
+		control.empty(),
+		Err(Error::MissingField("3..3".to_string()))
+	);
+	assert_eq!(
+		control.set_low(4),
+		Err(Error::OutOfRange("value fitting the bit range".to_string()))
+	);
+	assert_eq!(control.0, 0b0001_0011_0000);
+}
+
+#[test]
+fn beyond_word() {
+	let mut wide = Wide::from(u64::MAX);
+	assert_eq!(wide.last(), Ok(true));
+	assert_eq!(
+		wide.beyond(),
+		Err(Error::MissingField("64..65".to_string()))
+	);
+	assert_eq!(
+		wide.set_beyond(false),
+		Err(Error::MissingField("64..65".to_string()))
+	);
+	assert_eq!(wide.0, u64::MAX);
+}
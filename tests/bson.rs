@@ -0,0 +1,111 @@
+#![cfg(feature = "bson")]
+
+use ::bson::{oid::ObjectId, spec::BinarySubtype, Binary, Bson, DateTime, Decimal128, Document};
+use faible::{bson, faible, Faible};
+use std::str::FromStr;
+
+mod common;
+use common::Error;
+
+#[faible(
+	bson::Root::<Error>::new(),
+	weak = Document,
+	strong = Document,
+	names = "lowerCamelCase",
+	no_weak_conversions
+)]
+pub struct Order {
+	pub customer: Id,
+	pub created_at: Timestamp,
+	pub total: Price,
+	pub receipt: Blob,
+	pub shipping: Address,
+}
+
+#[faible(
+	bson::Document::<Error>::new(),
+	weak = Bson,
+	strong = Document,
+	names = "lowerCamelCase"
+)]
+pub struct Address {
+	pub street: Bson,
+	pub postal_code: Bson,
+}
+
+#[faible(bson::ObjectId::<Error>::new(), weak = Bson, strong = ObjectId)]
+pub struct Id;
+
+#[faible(bson::DateTime::<Error>::new(), weak = Bson, strong = DateTime)]
+pub struct Timestamp;
+
+#[faible(bson::Decimal128::<Error>::new(), weak = Bson, strong = Decimal128)]
+pub struct Price;
+
+#[faible(bson::Binary::<Error>::new(), weak = Bson, strong = Binary)]
+pub struct Blob;
+
+fn order() -> (ObjectId, Order) {
+	let customer = ObjectId::new();
+	let order = Order::from(::bson::doc! {
+		"customer": customer,
+		"createdAt": DateTime::from_millis(1_650_000_000_000),
+		"total": Decimal128::from_str("12.34").unwrap(),
+		"receipt": Binary { subtype: BinarySubtype::Generic, bytes: vec![1, 2, 3] },
+		"shipping": { "street": "Main Street 1", "postalCode": "12345" },
+	});
+	(customer, order)
+}
+
+#[test]
+fn typed_fields() {
+	let (customer, order) = order();
+	assert_eq!(order.customer().unwrap().as_strong().unwrap(), &customer);
+	assert_eq!(
+		order.created_at().unwrap().as_strong().unwrap(),
+		&DateTime::from_millis(1_650_000_000_000)
+	);
+	assert_eq!(
+		order.total().unwrap().as_strong().unwrap().to_string(),
+		"12.34"
+	);
+	assert_eq!(
+		order.receipt().unwrap().as_strong().unwrap().bytes,
+		vec![1, 2, 3]
+	);
+}
+
+#[test]
+fn nested_documents() {
+	let (_, mut order) = order();
+	assert_eq!(
+		order.shipping().unwrap().postal_code().unwrap(),
+		&Bson::from("12345")
+	);
+
+	let shipping = order.shipping_mut().unwrap();
+	let (street, prev) = shipping.insert_street(Bson::from("Side Street 2")).unwrap();
+	assert_eq!(street, &Bson::from("Side Street 2"));
+	assert_eq!(prev, Some(Bson::from("Main Street 1")));
+}
+
+#[test]
+fn schema_drift() {
+	let order = Order::from(::bson::doc! { "customer": "not an ID" });
+	assert_eq!(
+		order.customer().unwrap().as_strong().unwrap_err(),
+		Error::TypeMismatch("object ID".to_string())
+	);
+	assert_eq!(
+		order.total().err(),
+		Some(Error::MissingField("\"total\"".to_string()))
+	);
+}
+
+#[test]
+fn set_leaf() {
+	let (_, mut order) = order();
+	let customer = ObjectId::new();
+	order.set_customer(Id::from(customer)).unwrap();
+	assert_eq!(order.0.get_object_id("customer"), Ok(customer));
+}
@@ -0,0 +1,49 @@
+//! The [`faible::Error`] shared by the descriptor tests.
+
+use std::fmt::Debug;
+
+/// Records which constructor was called, with the `expected` description or field name.
+#[derive(Debug, PartialEq)]
+pub enum Error {
+	NoVariantRecognized,
+	AmbiguousVariant,
+	TypeMismatch(String),
+	MissingField(String),
+	ReadOnlyField(String),
+	OutOfRange(String),
+	UnknownVersion(String),
+	OutdatedVersion(String),
+}
+impl faible::Error for Error {
+	fn no_variant_recognized() -> Self {
+		Self::NoVariantRecognized
+	}
+
+	fn ambiguous_variant() -> Self {
+		Self::AmbiguousVariant
+	}
+
+	fn type_mismatch(expected: &str, _found: &dyn Debug) -> Self {
+		Self::TypeMismatch(expected.to_string())
+	}
+
+	fn missing_field(name: &dyn Debug) -> Self {
+		Self::MissingField(format!("{name:?}"))
+	}
+
+	fn read_only_field(name: &dyn Debug) -> Self {
+		Self::ReadOnlyField(format!("{name:?}"))
+	}
+
+	fn out_of_range(expected: &str, _found: &dyn Debug) -> Self {
+		Self::OutOfRange(expected.to_string())
+	}
+
+	fn unknown_version(found: &dyn Debug) -> Self {
+		Self::UnknownVersion(format!("{found:?}"))
+	}
+
+	fn outdated_version(found: &dyn Debug) -> Self {
+		Self::OutdatedVersion(format!("{found:?}"))
+	}
+}
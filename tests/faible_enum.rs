@@ -1,4 +1,11 @@
-use faible::{faible, Descriptor, VariantFieldAccessMut, VariantFieldAccessRef, VariantFilter};
+use faible::{
+	faible, Descriptor, UnitVariantAccess, VariantFieldAccessMut, VariantFieldAccessRef,
+	VariantFilter,
+};
+use std::{any::Any, fmt::Debug, ptr};
+
+mod common;
+use common::Error;
 
 const STRUCTURED: &str = "structured";
 
@@ -24,7 +31,12 @@ pub enum Value {
 	Structured { a: u8, b: u16 },
 }
 
-#[faible(ValueDescriptor::new(), no_weak_conversions, names = discriminant)]
+#[faible(
+	ValueDescriptor::new(),
+	no_weak_conversions,
+	names = discriminant,
+	set_variant
+)]
 pub enum Discriminated {
 	A = 1,
 	B = 2,
@@ -34,6 +46,38 @@ pub enum Discriminated {
 #[faible(ValueDescriptor::new(), no_weak_conversions)]
 pub enum Empty {}
 
+fn tag(name: &str) -> String {
+	format!("#{name}")
+}
+
+#[faible(ValueDescriptor::new(), no_weak_conversions)]
+pub enum Heading {
+	#[faible(_, name = _tag("_north"))]
+	North,
+	#[faible(_, name = _tag("_south"))]
+	South,
+}
+
+/// A variant `tag` with named fields of any type.
+#[derive(Debug)]
+pub struct Raw {
+	tag: String,
+	fields: Vec<(String, Box<dyn Any>)>,
+}
+impl Raw {
+	fn new(tag: impl ToString) -> Self {
+		Self {
+			tag: tag.to_string(),
+			fields: vec![],
+		}
+	}
+
+	fn with(mut self, name: impl ToString, value: impl Any) -> Self {
+		self.fields.push((name.to_string(), Box::new(value)));
+		self
+	}
+}
+
 pub struct ValueDescriptor;
 impl ValueDescriptor {
 	pub fn new() -> Self {
@@ -48,8 +92,8 @@ impl Default for ValueDescriptor {
 }
 
 impl Descriptor for ValueDescriptor {
-	type Weak = ();
-	type Strong = ();
+	type Weak = Raw;
+	type Strong = Raw;
 	type Error = Error;
 
 	fn strong<'a>(&self, weak: &'a Self::Weak) -> Result<&'a Self::Strong, Self::Error> {
@@ -72,41 +116,223 @@ impl Descriptor for ValueDescriptor {
 	}
 }
 
-impl<'a, Strong: 'a, E, N> VariantFilter<'a, Strong, E, N> for ValueDescriptor {
-	type CommonRef = &'a Strong;
-	type CommonMut = Vec<&'a mut ()>;
+type FieldsMut<'a> = Vec<(&'a String, &'a mut Box<dyn Any>)>;
+
+impl<'a, N: ToString> VariantFilter<'a, Raw, Error, N> for ValueDescriptor {
+	type CommonRef = &'a Raw;
+	type CommonMut = FieldsMut<'a>;
 
-	fn common(&self, strong: &'a Strong, name: N) -> Result<Option<Self::CommonRef>, E> {
-		Ok(Some(strong))
+	fn common(&self, strong: &'a Raw, name: N) -> Result<Option<Self::CommonRef>, Error> {
+		Ok(Some(strong).filter(|strong| strong.tag == name.to_string()))
 	}
 
-	fn check_mut(&self, strong: &mut Strong, name: N) -> Result<bool, E> {
-		unimplemented!()
+	fn common_mut(
+		&self,
+		strong: &'a mut Raw,
+		name: N,
+	) -> Result<Result<Self::CommonMut, &'a mut Raw>, Error> {
+		Ok(if strong.tag == name.to_string() {
+			Ok(strong
+				.fields
+				.iter_mut()
+				.map(|(name, value)| (&*name, value))
+				.collect())
+		} else {
+			Err(strong)
+		})
 	}
+}
 
-	fn common_mut(&self, strong: &'a mut Strong, name: N) -> Result<Self::CommonMut, E> {
-		unimplemented!()
+impl<N: ToString> UnitVariantAccess<Raw, Error, N> for ValueDescriptor {
+	fn set(&self, strong: &mut Raw, name: N) -> Result<(), Error> {
+		*strong = Raw::new(name);
+		Ok(())
 	}
 }
 
-impl<'a, Common: 'a + ?Sized, E, T: ?Sized, N> VariantFieldAccessRef<'a, Common, E, T, N>
-	for ValueDescriptor
-{
-	fn get(&self, common: &Common, name: N) -> Result<&'a T, E> {
-		unimplemented!()
+impl<'a, T: Any, N: ToString> VariantFieldAccessRef<'a, &'a Raw, Error, T, N> for ValueDescriptor {
+	fn get(&self, common: &&'a Raw, name: N) -> Result<&'a T, Error> {
+		let name = name.to_string();
+		let (_, value) = common
+			.fields
+			.iter()
+			.find(|(key, _)| *key == name)
+			.ok_or(Error::MissingField(name))?;
+		value
+			.downcast_ref()
+			.ok_or_else(|| Error::TypeMismatch(std::any::type_name::<T>().to_string()))
 	}
 }
-impl<'a, Common: 'a + ?Sized, E, T: ?Sized, N> VariantFieldAccessMut<'a, Common, E, T, N>
+
+impl<'a, T: Any, N: ToString> VariantFieldAccessMut<'a, FieldsMut<'a>, Error, T, N>
 	for ValueDescriptor
 {
-	fn get_mut(&self, common: &mut Common, name: N) -> Result<&'a mut T, E> {
-		unimplemented!()
+	fn get_mut(&self, common: &mut FieldsMut<'a>, name: N) -> Result<&'a mut T, Error> {
+		let name = name.to_string();
+		let index = common
+			.iter()
+			.position(|(key, _)| **key == name)
+			.ok_or(Error::MissingField(name))?;
+		common
+			.swap_remove(index)
+			.1
+			.downcast_mut()
+			.ok_or_else(|| Error::TypeMismatch(std::any::type_name::<T>().to_string()))
 	}
 }
 
-pub struct Error;
-impl faible::Error for Error {
-	fn no_variant_recognized() -> Self {
-		unimplemented!()
+#[test]
+fn as_variant() {
+	let null = Value::from(Raw::new("null"));
+	assert!(matches!(null.as_variant(), Ok(ValueVariantRef::Null)));
+
+	let none = Value::from(Raw::new("none"));
+	assert!(matches!(none.as_variant(), Ok(ValueVariantRef::None())));
+
+	let bool = Value::from(Raw::new("bool").with(0, true));
+	assert!(matches!(
+		bool.as_variant(),
+		Ok(ValueVariantRef::Bool(&true))
+	));
+
+	let pointer = Value::from(Raw::new(0).with(0, ptr::null_mut::<()>()));
+	assert!(matches!(
+		pointer.as_variant(),
+		Ok(ValueVariantRef::Pointer(pointer)) if pointer.is_null()
+	));
+
+	let structured = Value::from(Raw::new(STRUCTURED).with("a", 1_u8).with("b", 2_u16));
+	assert!(matches!(
+		structured.as_variant(),
+		Ok(ValueVariantRef::Structured { a: &1, b: &2 })
+	));
+
+	let discriminated = Discriminated::from(Raw::new(2));
+	assert!(matches!(
+		discriminated.as_variant(),
+		Ok(DiscriminatedVariantRef::B)
+	));
+}
+
+#[test]
+fn as_variant_mut() {
+	let mut structured = Value::from(Raw::new(STRUCTURED).with("b", 2_u16).with("a", 1_u8));
+	if let Ok(ValueVariantMut::Structured { a, b }) = structured.as_variant_mut() {
+		*a += 10;
+		*b += 20;
+	} else {
+		panic!("Expected a structured value.");
+	}
+	assert!(matches!(
+		structured.as_variant(),
+		Ok(ValueVariantRef::Structured { a: &11, b: &22 })
+	));
+
+	let mut discriminated = Discriminated::from(Raw::new(3));
+	assert!(matches!(
+		discriminated.as_variant_mut(),
+		Ok(DiscriminatedVariantMut::C)
+	));
+}
+
+#[test]
+fn errors() {
+	let mut unknown = Value::from(Raw::new("unknown"));
+	assert_eq!(unknown.as_variant().err(), Some(Error::NoVariantRecognized));
+	assert_eq!(
+		unknown.as_variant_mut().err(),
+		Some(Error::NoVariantRecognized)
+	);
+
+	let mut incomplete = Value::from(Raw::new(STRUCTURED).with("a", 1_u8));
+	assert_eq!(
+		incomplete.as_variant().err(),
+		Some(Error::MissingField("b".to_string()))
+	);
+	assert_eq!(
+		incomplete.as_variant_mut().err(),
+		Some(Error::MissingField("b".to_string()))
+	);
+
+	let mistyped = Value::from(Raw::new("bool").with(0, 1_u8));
+	assert_eq!(
+		mistyped.as_variant().err(),
+		Some(Error::TypeMismatch("bool".to_string()))
+	);
+
+	let mut empty = Empty::from(Raw::new("anything"));
+	assert_eq!(empty.as_variant().err(), Some(Error::NoVariantRecognized));
+	assert_eq!(
+		empty.as_variant_mut().err(),
+		Some(Error::NoVariantRecognized)
+	);
+}
+
+#[test]
+fn variant_accessors() {
+	let mut structured = Value::from(Raw::new(STRUCTURED).with("a", 1_u8).with("b", 2_u16));
+	assert_eq!(structured.is_structured(), Ok(true));
+	assert_eq!(structured.is_null(), Ok(false));
+	assert_eq!(structured.as_structured(), Ok(Some((&1, &2))));
+	assert_eq!(structured.as_bool(), Ok(None));
+
+	if let Ok(Some((a, _))) = structured.as_structured_mut() {
+		*a = 3;
 	}
+	assert_eq!(structured.as_structured(), Ok(Some((&3, &2))));
+	assert_eq!(
+		structured.as_pointer_mut().map(|pointer| pointer.is_some()),
+		Ok(false)
+	);
+
+	let bool = Value::from(Raw::new("bool").with(0, false));
+	assert_eq!(bool.as_bool(), Ok(Some(&false)));
+
+	let mut null = Value::from(Raw::new("null"));
+	assert_eq!(null.is_null(), Ok(true));
+	assert_eq!(null.as_null(), Ok(Some(())));
+	assert_eq!(null.as_null_mut(), Ok(Some(())));
+
+	let incomplete = Value::from(Raw::new(STRUCTURED).with("a", 1_u8));
+	assert_eq!(incomplete.is_structured(), Ok(true));
+	assert_eq!(
+		incomplete.as_structured(),
+		Err(Error::MissingField("b".to_string()))
+	);
+}
+
+#[test]
+fn variant() {
+	let incomplete = Value::from(Raw::new(STRUCTURED).with("a", 1_u8));
+	assert_eq!(incomplete.variant(), Ok(ValueVariantKind::Structured));
+
+	let unknown = Value::from(Raw::new("unknown"));
+	assert_eq!(unknown.variant(), Err(Error::NoVariantRecognized));
+
+	assert_eq!(
+		format!("{:?}", ValueVariantKind::Null.weak_name()),
+		"Some(\"null\")"
+	);
+	assert_eq!(
+		format!("{:?}", DiscriminatedVariantKind::B.weak_name()),
+		"Some(2)"
+	);
+
+	let south = Heading::from(Raw::new("#south"));
+	assert_eq!(south.variant(), Ok(HeadingVariantKind::South));
+}
+
+#[test]
+fn c_like() {
+	let mut discriminated = Discriminated::from(Raw::new(2));
+	assert!(matches!(
+		discriminated.to_rust_enum(),
+		Ok(DiscriminatedVariantOwned::B)
+	));
+
+	discriminated
+		.set_variant(DiscriminatedVariantOwned::C)
+		.unwrap();
+	assert_eq!(discriminated.0.tag, "3");
+	assert_eq!(discriminated.variant(), Ok(DiscriminatedVariantKind::C));
 }
@@ -0,0 +1,156 @@
+use faible::{faible, Classification, Descriptor, VariantFilter};
+use std::{cell::Cell, fmt::Debug};
+
+mod common;
+use common::Error;
+
+#[faible(OpcodeDescriptor, names = index, no_weak_conversions)]
+pub enum Opcode {
+	Nop,
+	Load,
+	Store,
+	Halt,
+}
+
+#[faible(OpcodeDescriptor, names = index, no_weak_conversions)]
+pub enum Instruction {
+	Nop,
+	Wrapped(#[faible(view)] Opcode),
+	#[faible(other = weak)]
+	Unknown(u8),
+}
+
+thread_local! {
+	static CLASSIFICATIONS: Cell<usize> = const { Cell::new(0) };
+	static CHECKS: Cell<usize> = const { Cell::new(0) };
+}
+
+fn counts() -> (usize, usize) {
+	(CLASSIFICATIONS.with(Cell::take), CHECKS.with(Cell::take))
+}
+
+pub struct OpcodeDescriptor;
+
+impl Descriptor for OpcodeDescriptor {
+	type Weak = u8;
+	type Strong = u8;
+	type Error = Error;
+
+	fn strong<'a>(&self, weak: &'a Self::Weak) -> Result<&'a Self::Strong, Self::Error> {
+		Ok(weak)
+	}
+
+	fn strong_mut<'a>(
+		&self,
+		weak: &'a mut Self::Weak,
+	) -> Result<&'a mut Self::Strong, Self::Error> {
+		Ok(weak)
+	}
+
+	fn strong_into_weak(&self, strong: Self::Strong) -> Self::Weak {
+		strong
+	}
+
+	fn try_weak_into_strong(&self, weak: Self::Weak) -> Result<Self::Strong, Self::Error> {
+		Ok(weak)
+	}
+}
+
+impl<'a> VariantFilter<'a, u8, Error, u8> for OpcodeDescriptor {
+	type CommonRef = ();
+	type CommonMut = ();
+
+	fn common(&self, strong: &'a u8, name: u8) -> Result<Option<Self::CommonRef>, Error> {
+		CHECKS.with(|checks| checks.set(checks.get() + 1));
+		Ok(Some(()).filter(|()| *strong == name))
+	}
+
+	fn common_mut(
+		&self,
+		strong: &'a mut u8,
+		name: u8,
+	) -> Result<Result<Self::CommonMut, &'a mut u8>, Error> {
+		Ok(self.common(strong, name)?.ok_or(strong))
+	}
+
+	fn classify(&self, strong: &u8, names: &[u8]) -> Result<Classification, Error> {
+		CLASSIFICATIONS.with(|classifications| classifications.set(classifications.get() + 1));
+		// Opcode 3 is left to sequential checks.
+		Ok(match usize::from(*strong) {
+			3 => Classification::Unknown,
+			index if index < names.len() => Classification::Variant(index),
+			_ => Classification::Unrecognized,
+		})
+	}
+}
+
+#[test]
+fn classified() {
+	counts();
+
+	let mut store = Opcode::from(2);
+	assert!(matches!(store.as_variant(), Ok(OpcodeVariantRef::Store)));
+	assert_eq!(counts(), (1, 1));
+
+	// The variant is recognised with shared borrows, and then borrowed mutably once through `common_mut`.
+	assert!(matches!(
+		store.as_variant_mut(),
+		Ok(OpcodeVariantMut::Store)
+	));
+	assert_eq!(counts(), (1, 2));
+}
+
+#[test]
+fn sequential_fallback() {
+	counts();
+
+	let halt = Opcode::from(3);
+	assert!(matches!(halt.as_variant(), Ok(OpcodeVariantRef::Halt)));
+	assert_eq!(counts(), (1, 4));
+}
+
+#[test]
+fn unrecognized() {
+	counts();
+
+	let unknown = Opcode::from(4);
+	assert_eq!(unknown.as_variant().err(), Some(Error::NoVariantRecognized));
+	assert_eq!(counts(), (1, 0));
+	assert_eq!(unknown.variant().err(), Some(Error::NoVariantRecognized));
+	assert_eq!(counts(), (1, 0));
+}
+
+#[test]
+fn variant() {
+	counts();
+
+	let load = Opcode::from(1);
+	assert_eq!(load.variant(), Ok(OpcodeVariantKind::Load));
+	assert_eq!(counts(), (1, 1));
+}
+
+#[test]
+fn single_mutable_pass() {
+	counts();
+
+	let mut wrapped = Instruction::from(1);
+	if let Ok(InstructionVariantMut::Wrapped(opcode)) = wrapped.as_variant_mut() {
+		opcode.0 = 0;
+	}
+	assert_eq!(counts(), (1, 1));
+	assert!(matches!(
+		wrapped.as_variant(),
+		Ok(InstructionVariantRef::Nop)
+	));
+	counts();
+
+	let mut unknown = Instruction::from(9);
+	if let Ok(InstructionVariantMut::Unknown(raw)) = unknown.as_variant_mut() {
+		*raw = 0;
+	}
+	assert_eq!(counts(), (1, 0));
+	assert!(matches!(
+		unknown.as_variant(),
+		Ok(InstructionVariantRef::Nop)
+	));
+}
@@ -1,6 +1,6 @@
 use faible::{faible, Descriptor, FieldAccess, View};
 use serde_json::{map::Entry, Map, Number, Value};
-use std::mem;
+use std::{fmt::Debug, mem};
 use tap::Pipe;
 
 #[faible(JsonObjectDescriptor("MapInfo"), faible = ::faible, names = "lowerCamelCase")]
@@ -273,4 +273,12 @@ impl faible::Error for Error {
 	fn no_variant_recognized() -> Self {
 		unimplemented!()
 	}
+
+	fn type_mismatch(expected: &str, found: &dyn Debug) -> Self {
+		Self::new(format!("Expected {expected}, but found {found:?}."))
+	}
+
+	fn missing_field(name: &dyn Debug) -> Self {
+		Self::new(format!("Expected field {name:?}."))
+	}
 }
@@ -1,5 +1,5 @@
 use faible::{faible, Descriptor, UnionFieldAccess};
-use std::{convert::Infallible, marker::PhantomData, mem, ptr::NonNull};
+use std::{marker::PhantomData, mem, ptr::NonNull};
 
 #[faible(NullableDescriptor::<T>::new(), names = "_unused", no_weak_conversions)]
 pub union Nullable<T: 'static + ?Sized> {
@@ -14,6 +14,12 @@ impl<T: ?Sized> NullableDescriptor<T> {
 	}
 }
 
+impl<T: ?Sized> Default for NullableDescriptor<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 impl<T: 'static + ?Sized> Descriptor for NullableDescriptor<T> {
 	type Weak = *mut T;
 	type Strong = *mut T;
@@ -40,19 +46,19 @@ impl<T: 'static + ?Sized> Descriptor for NullableDescriptor<T> {
 }
 
 impl<T: ?Sized, E, N> UnionFieldAccess<*mut T, E, NonNull<T>, N> for NullableDescriptor<T> {
-	fn get<'a>(&self, strong: &'a *mut T, name: N) -> Result<Option<&'a NonNull<T>>, E> {
+	fn get<'a>(&self, _strong: &'a *mut T, _name: N) -> Result<Option<&'a NonNull<T>>, E> {
 		unimplemented!()
 	}
 
 	fn get_mut<'a>(
 		&self,
-		strong: &'a mut *mut T,
-		name: N,
+		_strong: &'a mut *mut T,
+		_name: N,
 	) -> Result<Option<&'a mut NonNull<T>>, E> {
 		unimplemented!()
 	}
 
-	fn set(&self, strong: &mut *mut T, name: N, value: NonNull<T>) -> Result<(), E>
+	fn set(&self, _strong: &mut *mut T, _name: N, _value: NonNull<T>) -> Result<(), E>
 	where
 		NonNull<T>: Sized,
 	{
@@ -61,9 +67,9 @@ impl<T: ?Sized, E, N> UnionFieldAccess<*mut T, E, NonNull<T>, N> for NullableDes
 
 	fn insert<'a>(
 		&self,
-		strong: &'a mut *mut T,
-		name: N,
-		value: NonNull<T>,
+		_strong: &'a mut *mut T,
+		_name: N,
+		_value: NonNull<T>,
 	) -> Result<(&'a mut NonNull<T>, Option<NonNull<T>>), E>
 	where
 		NonNull<T>: Sized,
@@ -73,15 +79,15 @@ impl<T: ?Sized, E, N> UnionFieldAccess<*mut T, E, NonNull<T>, N> for NullableDes
 }
 
 impl<T: ?Sized, E, N> UnionFieldAccess<*mut T, E, *mut T, N> for NullableDescriptor<T> {
-	fn get<'a>(&self, strong: &'a *mut T, name: N) -> Result<Option<&'a *mut T>, E> {
+	fn get<'a>(&self, strong: &'a *mut T, _name: N) -> Result<Option<&'a *mut T>, E> {
 		Ok(Some(strong))
 	}
 
-	fn get_mut<'a>(&self, strong: &'a mut *mut T, name: N) -> Result<Option<&'a mut *mut T>, E> {
+	fn get_mut<'a>(&self, strong: &'a mut *mut T, _name: N) -> Result<Option<&'a mut *mut T>, E> {
 		Ok(Some(strong))
 	}
 
-	fn set(&self, strong: &mut *mut T, name: N, value: *mut T) -> Result<(), E>
+	fn set(&self, strong: &mut *mut T, _name: N, value: *mut T) -> Result<(), E>
 	where
 		*mut T: Sized,
 	{
@@ -92,7 +98,7 @@ impl<T: ?Sized, E, N> UnionFieldAccess<*mut T, E, *mut T, N> for NullableDescrip
 	fn insert<'a>(
 		&self,
 		strong: &'a mut *mut T,
-		name: N,
+		_name: N,
 		value: *mut T,
 	) -> Result<(&'a mut *mut T, Option<*mut T>), E>
 	where
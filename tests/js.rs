@@ -0,0 +1,103 @@
+#![cfg(all(feature = "js", target_arch = "wasm32"))]
+
+use faible::{faible, js, Descriptor, Faible, FieldValueAccess};
+use js_sys::{JsString, Number, Object, JSON};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+mod common;
+use common::Error;
+
+#[faible(
+	js::Object::<Error>::new(),
+	weak = JsValue,
+	strong = Object,
+	names = "verbatim"
+)]
+pub struct Config {
+	#[faible(by_value)]
+	pub retries: Count,
+	#[faible(by_value)]
+	pub label: Label,
+	#[faible(by_value, name = "_label")]
+	pub label_as_count: Count,
+	#[faible(by_value)]
+	pub nested: Config,
+}
+
+#[faible(js::Number::<Error>::new(), weak = JsValue, strong = Number)]
+pub struct Count;
+
+#[faible(js::String::<Error>::new(), weak = JsValue, strong = JsString)]
+pub struct Label;
+
+fn config() -> Config {
+	Config::from(JSON::parse(r#"{ "retries": 3, "label": "primary", "nested": {} }"#).unwrap())
+}
+
+#[wasm_bindgen_test]
+fn typed_properties() {
+	let config = config();
+
+	let retries = config.retries().unwrap();
+	assert_eq!(retries.as_strong().unwrap().value_of(), 3.0);
+
+	let label = config.label().unwrap();
+	assert_eq!(label.as_strong().unwrap(), "primary");
+
+	let not_a_number = config.label_as_count().unwrap();
+	assert_eq!(
+		not_a_number.as_strong().unwrap_err(),
+		Error::TypeMismatch("js_sys::Number".to_string())
+	);
+}
+
+#[wasm_bindgen_test]
+fn handles_share_state() {
+	let config = config();
+
+	let mut nested = config.nested().unwrap();
+	js::Object::<Error>::new()
+		.set(nested.as_strong_mut().unwrap(), "enabled", JsValue::TRUE)
+		.unwrap();
+
+	assert_eq!(
+		JSON::stringify(&config.0).unwrap(),
+		r#"{"retries":3,"label":"primary","nested":{"enabled":true}}"#
+	);
+}
+
+#[wasm_bindgen_test]
+fn missing_and_mismatched() {
+	let descriptor = js::Object::<Error>::new();
+	let object: Object = config().0.into();
+	assert_eq!(
+		FieldValueAccess::<_, _, JsValue, _>::get(&descriptor, &object, "absent").unwrap_err(),
+		Error::MissingField("\"absent\"".to_string())
+	);
+
+	let mut frozen = Object::freeze(&object);
+	assert_eq!(
+		FieldValueAccess::<_, _, JsValue, _>::set(
+			&descriptor,
+			&mut frozen,
+			"retries",
+			JsValue::from(4)
+		)
+		.unwrap_err(),
+		Error::ReadOnlyField("\"retries\"".to_string())
+	);
+
+	let mut config = Config::from(JsValue::NULL);
+	assert_eq!(
+		config
+			.set_retries(Count::from(JsValue::from(1)))
+			.unwrap_err(),
+		Error::TypeMismatch("js_sys::Object".to_string())
+	);
+
+	assert_eq!(
+		descriptor.try_weak_into_strong(JsValue::from(1)).err(),
+		Some(Error::TypeMismatch("js_sys::Object".to_string()))
+	);
+}
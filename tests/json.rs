@@ -0,0 +1,300 @@
+#![cfg(feature = "serde_json")]
+
+use faible::{faible, json};
+use serde_json::{json, Map, Value};
+
+mod common;
+use common::Error;
+
+#[faible(
+	json::Object::<Error>::new(),
+	weak = Value,
+	strong = Map<String, Value>,
+	names = "snake_case"
+)]
+pub struct Reading {
+	pub name: Value,
+	#[faible(json::Number::<Error>::new(), by_value)]
+	pub channel: u8,
+	#[faible(json::Number::<Error>::new(), by_value)]
+	pub offset: i16,
+	#[faible(json::Number::<Error>::new(), by_value)]
+	pub count: u64,
+	#[faible(json::Number::<Error>::new(), by_value)]
+	pub delta: i64,
+	#[faible(json::Number::<Error>::new(), by_value)]
+	pub gain: f32,
+	#[faible(json::Number::<Error>::new(), by_value)]
+	pub value: f64,
+	#[faible(json::Number::<Error>::new(), by_value, name = "_value")]
+	pub value_as_u32: u32,
+	#[faible(json::Number::<Error>::new(), by_value, name = "_name")]
+	pub name_as_u32: u32,
+}
+
+#[faible(
+	json::Object::<Error>::new(),
+	weak = Value,
+	strong = Map<String, Value>,
+	names = "lowerCamelCase"
+)]
+pub struct Position {
+	#[faible(json::Number::<Error>::new(), by_value)]
+	pub scroll_x: i32,
+	#[faible(json::Number::<Error>::new(), by_value)]
+	pub scroll_y: i32,
+}
+
+#[faible(
+	json::Object::<Error>::new(),
+	weak = Value,
+	strong = Map<String, Value>,
+	names = "lowerCamelCase"
+)]
+pub struct MapInfo {
+	pub title: Value,
+	#[faible(flatten)]
+	pub position: Position,
+}
+
+#[faible(
+	json::Object::<Error>::new(),
+	weak = Value,
+	strong = Map<String, Value>,
+	names = "lowerCamelCase"
+)]
+pub struct Node {
+	#[faible(alias = "snake_case", alias = "_parent")]
+	pub parent_id: Value,
+	#[faible(json::Number::<Error>::new(), by_value, alias = "snake_case")]
+	pub child_count: u32,
+}
+
+#[faible(
+	json::Object::<Error>::new(),
+	weak = Value,
+	strong = Map<String, Value>,
+	names = "snake_case",
+	version_field = "v",
+	version = 2,
+	migrate = migrate_document
+)]
+pub struct Document {
+	pub title: Value,
+	pub tags: Value,
+	#[faible(flatten)]
+	pub position: Position,
+}
+
+fn migrate_document(version: u64, weak: &mut Value) -> Result<(), Error> {
+	let object = weak
+		.as_object_mut()
+		.ok_or_else(|| Error::TypeMismatch("object".to_string()))?;
+	match version {
+		0 => {
+			let name = object.remove("name").unwrap_or(Value::Null);
+			object.insert("title".to_string(), name);
+		}
+		1 => {
+			if object.contains_key("tags") {
+				return Err(Error::TypeMismatch("absent tags".to_string()));
+			}
+			object.insert("tags".to_string(), json!([]));
+		}
+		_ => return Err(Error::UnknownVersion(version.to_string())),
+	}
+	Ok(())
+}
+
+fn reading() -> Reading {
+	Reading::from(json!({
+		"name": "thermometer",
+		"channel": 3,
+		"offset": -40,
+		"count": u64::MAX,
+		"delta": i64::MIN,
+		"gain": 0.5,
+		"value": 21.5,
+	}))
+}
+
+#[test]
+fn in_range() {
+	let reading = reading();
+	assert_eq!(reading.channel(), Ok(3));
+	assert_eq!(reading.offset(), Ok(-40));
+	assert_eq!(reading.count(), Ok(u64::MAX));
+	assert_eq!(reading.delta(), Ok(i64::MIN));
+	assert_eq!(reading.gain(), Ok(0.5));
+	assert_eq!(reading.value(), Ok(21.5));
+}
+
+#[test]
+fn integral_floats() {
+	let reading = Reading::from(json!({ "channel": 4.0, "count": 1e3 }));
+	assert_eq!(reading.channel(), Ok(4));
+	assert_eq!(reading.count(), Ok(1000));
+}
+
+#[test]
+fn out_of_range() {
+	let reading = Reading::from(json!({
+		"channel": 256,
+		"offset": 32768,
+		"count": -1,
+		"delta": 1e19,
+		"gain": 1e39,
+		"value": 1.5,
+	}));
+	assert_eq!(reading.channel(), Err(Error::OutOfRange("u8".to_string())));
+	assert_eq!(reading.offset(), Err(Error::OutOfRange("i16".to_string())));
+	assert_eq!(reading.count(), Err(Error::OutOfRange("u64".to_string())));
+	assert_eq!(reading.delta(), Err(Error::OutOfRange("i64".to_string())));
+	assert_eq!(reading.gain(), Err(Error::OutOfRange("f32".to_string())));
+	assert_eq!(
+		reading.value_as_u32(),
+		Err(Error::OutOfRange("u32".to_string()))
+	);
+}
+
+#[cfg(feature = "arbitrary_precision")]
+#[test]
+fn beyond_f64() {
+	let value: Value = serde_json::from_str(r#"{ "value": 1e400 }"#).unwrap();
+	assert_eq!(
+		Reading::from(value).value(),
+		Err(Error::OutOfRange("f64".to_string()))
+	);
+}
+
+#[test]
+fn not_a_number() {
+	let reading = reading();
+	assert_eq!(
+		reading.name_as_u32(),
+		Err(Error::TypeMismatch("number".to_string()))
+	);
+	assert_eq!(
+		Reading::from(json!({})).channel(),
+		Err(Error::MissingField("\"channel\"".to_string()))
+	);
+}
+
+#[test]
+fn set() {
+	let mut reading = Reading::from(json!({ "name": "hygrometer" }));
+	reading.set_channel(7).unwrap();
+	reading.set_delta(-1).unwrap();
+	reading.set_gain(0.25).unwrap();
+	assert_eq!(
+		reading.set_value(f64::NAN),
+		Err(Error::OutOfRange("finite number".to_string()))
+	);
+	assert_eq!(
+		reading.set_gain(f32::INFINITY),
+		Err(Error::OutOfRange("finite number".to_string()))
+	);
+	assert_eq!(
+		reading.0,
+		json!({ "name": "hygrometer", "channel": 7, "delta": -1, "gain": 0.25 })
+	);
+}
+
+#[test]
+fn flatten() {
+	let mut map = MapInfo::from(json!({ "title": "overworld", "scrollX": 3, "scrollY": -4 }));
+	assert_eq!(map.position().scroll_x(), Ok(3));
+	assert_eq!(map.position().scroll_y(), Ok(-4));
+
+	map.position_mut().set_scroll_y(5).unwrap();
+	assert_eq!(
+		map.0,
+		json!({ "title": "overworld", "scrollX": 3, "scrollY": 5 })
+	);
+	assert_eq!(map.title(), Ok(&json!("overworld")));
+}
+
+#[test]
+fn aliases() {
+	let mut legacy = Node::from(json!({ "parent_id": 1, "parent": 2, "child_count": 3 }));
+	assert_eq!(legacy.parent_id(), Ok(&json!(1)));
+	assert_eq!(legacy.child_count(), Ok(3));
+	*legacy.parent_id_mut().unwrap() = json!(4);
+	assert_eq!(legacy.0["parent_id"], 4);
+
+	legacy.set_parent_id(json!(5)).unwrap();
+	legacy.set_child_count(6).unwrap();
+	assert_eq!(legacy.parent_id(), Ok(&json!(5)));
+	legacy.remove_parent_id_aliases().unwrap();
+	legacy.remove_child_count_aliases().unwrap();
+	assert_eq!(legacy.0, json!({ "parentId": 5, "childCount": 6 }));
+
+	let older = Node::from(json!({ "parent": 7 }));
+	assert_eq!(older.parent_id(), Ok(&json!(7)));
+
+	let orphan = Node::from(json!({}));
+	assert_eq!(
+		orphan.parent_id(),
+		Err(Error::MissingField("\"parentId\"".to_string()))
+	);
+}
+
+#[test]
+fn versions() {
+	let current = Document::from(json!({ "v": 2, "title": "faible", "tags": [] }));
+	assert_eq!(current.title(), Ok(&json!("faible")));
+
+	let mut legacy = Document::from(json!({ "name": "faible" }));
+	assert_eq!(legacy.title(), Err(Error::OutdatedVersion("0".to_string())));
+	legacy.upgrade().unwrap();
+	assert_eq!(legacy.0, json!({ "v": 2, "title": "faible", "tags": [] }));
+	assert_eq!(legacy.title(), Ok(&json!("faible")));
+
+	let mut unscrolled = Document::from(json!({ "name": "faible" }));
+	assert_eq!(
+		unscrolled.position().err(),
+		Some(Error::OutdatedVersion("0".to_string()))
+	);
+	unscrolled.position_mut().unwrap().set_scroll_x(1).unwrap();
+	assert_eq!(
+		unscrolled.0,
+		json!({ "v": 2, "title": "faible", "tags": [], "scrollX": 1 })
+	);
+	assert_eq!(unscrolled.position().unwrap().scroll_x(), Ok(1));
+
+	let mut outdated = Document::from(json!({ "v": 1, "title": "faible" }));
+	outdated.set_tags(json!(["json"])).unwrap();
+	assert_eq!(
+		outdated.0,
+		json!({ "v": 2, "title": "faible", "tags": ["json"] })
+	);
+
+	let mut interrupted = Document::from(json!({ "name": "faible", "tags": "json" }));
+	for _ in 0..2 {
+		assert_eq!(
+			interrupted.upgrade(),
+			Err(Error::TypeMismatch("absent tags".to_string()))
+		);
+		assert_eq!(
+			interrupted.0,
+			json!({ "v": 1, "title": "faible", "tags": "json" })
+		);
+	}
+
+	let mut future = Document::from(json!({ "v": 3 }));
+	assert_eq!(
+		future.upgrade(),
+		Err(Error::UnknownVersion("3".to_string()))
+	);
+	assert_eq!(future.title(), Err(Error::UnknownVersion("3".to_string())));
+	assert_eq!(
+		future.title_mut().err(),
+		Some(Error::UnknownVersion("3".to_string()))
+	);
+
+	let mut mistyped = Document::from(json!({ "v": "2" }));
+	assert_eq!(
+		mistyped.upgrade(),
+		Err(Error::TypeMismatch("version number".to_string()))
+	);
+}
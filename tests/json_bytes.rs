@@ -0,0 +1,108 @@
+#![cfg(all(feature = "serde_json", feature = "base64", feature = "hex"))]
+
+use faible::{faible, json};
+use serde_json::{json, Map, Value};
+use std::fmt::Debug;
+
+#[faible(
+	json::Object::<Error>::new(),
+	weak = Value,
+	strong = Map<String, Value>,
+	names = "snake_case"
+)]
+pub struct Upload {
+	#[faible(json::Base64::<Error>::standard(), by_value)]
+	pub content: Vec<u8>,
+	#[faible(json::Base64::<Error>::url_safe_no_pad(), by_value)]
+	pub token: Vec<u8>,
+	#[faible(json::Hex::<Error>::lower(), by_value)]
+	pub sha1: Vec<u8>,
+	#[faible(json::Hex::<Error>::upper(), by_value)]
+	pub crc: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+	NoVariantRecognized,
+	TypeMismatch(String, String),
+	MissingField(String),
+}
+impl faible::Error for Error {
+	fn no_variant_recognized() -> Self {
+		Self::NoVariantRecognized
+	}
+
+	fn type_mismatch(expected: &str, found: &dyn Debug) -> Self {
+		Self::TypeMismatch(expected.to_string(), format!("{found:?}"))
+	}
+
+	fn missing_field(name: &dyn Debug) -> Self {
+		Self::MissingField(format!("{name:?}"))
+	}
+}
+
+#[test]
+fn decode() {
+	let upload = Upload::from(json!({
+		"content": "ZmFpYmxl",
+		"token": "-_8",
+		"sha1": "00fF10",
+		"crc": "CAFE",
+	}));
+	assert_eq!(upload.content().unwrap(), b"faible");
+	assert_eq!(upload.token().unwrap(), [0xFB, 0xFF]);
+	assert_eq!(upload.sha1().unwrap(), [0x00, 0xFF, 0x10]);
+	assert_eq!(upload.crc().unwrap(), [0xCA, 0xFE]);
+}
+
+#[test]
+fn encode() {
+	let mut upload = Upload::from(json!({}));
+	upload.set_content(b"fai".to_vec()).unwrap();
+	upload.set_token(vec![0xFB, 0xFF]).unwrap();
+	upload.set_sha1(vec![0xAB, 0x01]).unwrap();
+	upload.set_crc(vec![0xAB, 0x01]).unwrap();
+	assert_eq!(
+		upload.0,
+		json!({ "content": "ZmFp", "token": "-_8", "sha1": "ab01", "crc": "AB01" })
+	);
+}
+
+#[test]
+fn invalid() {
+	let upload = Upload::from(json!({
+		"content": "ZmFpYmxl=",
+		"token": "-_8=",
+		"sha1": "abc",
+		"crc": 1,
+	}));
+	assert_eq!(
+		upload.content().unwrap_err(),
+		Error::TypeMismatch(
+			"base64 string".to_string(),
+			r#"("content", String("ZmFpYmxl="))"#.to_string()
+		)
+	);
+	assert!(matches!(
+		upload.token().unwrap_err(),
+		Error::TypeMismatch(expected, _) if expected == "base64 string"
+	));
+	assert_eq!(
+		upload.sha1().unwrap_err(),
+		Error::TypeMismatch(
+			"hexadecimal string".to_string(),
+			r#"("sha1", String("abc"))"#.to_string()
+		)
+	);
+	assert_eq!(
+		upload.crc().unwrap_err(),
+		Error::TypeMismatch(
+			"hexadecimal string".to_string(),
+			r#"("crc", Number(1))"#.to_string()
+		)
+	);
+	assert_eq!(
+		Upload::from(json!({})).content().unwrap_err(),
+		Error::MissingField("\"content\"".to_string())
+	);
+}
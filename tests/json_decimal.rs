@@ -0,0 +1,94 @@
+#![cfg(all(feature = "serde_json", feature = "rust_decimal"))]
+
+use faible::{faible, json};
+use rust_decimal::Decimal;
+use serde_json::{json, Map, Value};
+use std::str::FromStr;
+
+mod common;
+use common::Error;
+
+#[faible(
+	json::Object::<Error>::new(),
+	weak = Value,
+	strong = Map<String, Value>,
+	names = "snake_case"
+)]
+pub struct LineItem {
+	pub sku: Value,
+	#[faible(json::Decimal::<Error>::new(), by_value)]
+	pub price: Decimal,
+	#[faible(json::Decimal::<Error>::new(), by_value)]
+	pub discount: Decimal,
+	#[faible(json::Decimal::<Error>::new(), by_value, name = "_sku")]
+	pub sku_as_decimal: Decimal,
+}
+
+fn decimal(text: &str) -> Decimal {
+	Decimal::from_str(text).unwrap()
+}
+
+#[test]
+fn numbers_and_strings() {
+	let item = LineItem::from(json!({ "sku": "A-1", "price": 19.99, "discount": "0.10" }));
+	assert_eq!(item.price(), Ok(decimal("19.99")));
+	assert_eq!(item.discount().unwrap().to_string(), "0.10");
+	assert_eq!(
+		item.sku_as_decimal(),
+		Err(Error::TypeMismatch("decimal".to_string()))
+	);
+
+	let item = LineItem::from(json!({ "price": 1.5e2 }));
+	assert_eq!(item.price(), Ok(decimal("150")));
+}
+
+#[test]
+fn set_keeps_representation() {
+	let mut item = LineItem::from(json!({ "price": 1, "discount": "0" }));
+	item.set_price(decimal("0.5")).unwrap();
+	item.set_discount(decimal("12345678901234567890.12345678"))
+		.unwrap();
+	assert_eq!(
+		item.0,
+		json!({ "price": 0.5, "discount": "12345678901234567890.12345678" })
+	);
+	assert_eq!(
+		item.discount(),
+		Ok(decimal("12345678901234567890.12345678"))
+	);
+}
+
+#[cfg(feature = "arbitrary_precision")]
+#[test]
+fn exact_numbers() {
+	let value: Value =
+		serde_json::from_str(r#"{ "price": 0.1000000000000000000000000001 }"#).unwrap();
+	let mut item = LineItem::from(value);
+	assert_eq!(item.price(), Ok(decimal("0.1000000000000000000000000001")));
+
+	item.set_price(decimal("12345678901234567890.12345678"))
+		.unwrap();
+	assert_eq!(
+		serde_json::to_string(&item.0).unwrap(),
+		r#"{"price":12345678901234567890.12345678}"#
+	);
+}
+
+#[cfg(not(feature = "arbitrary_precision"))]
+#[test]
+fn inexact_numbers() {
+	let mut item = LineItem::from(json!({}));
+	assert_eq!(
+		item.set_price(decimal("12345678901234567890.12345678")),
+		Err(Error::OutOfRange(
+			"exactly representable number".to_string()
+		))
+	);
+	assert_eq!(
+		item.set_price(decimal("1.10")),
+		Err(Error::OutOfRange(
+			"exactly representable number".to_string()
+		))
+	);
+	assert_eq!(item.0, json!({}));
+}
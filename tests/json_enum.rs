@@ -0,0 +1,581 @@
+#![cfg(feature = "serde_json")]
+
+use faible::{faible, json};
+use serde_json::{json, Map, Number, Value};
+use std::fmt::Debug;
+
+mod common;
+use common::Error;
+
+#[faible(
+	json::InternallyTagged::<Error>::new("type"),
+	weak = Value,
+	strong = Map<String, Value>,
+	names = "snake_case",
+	nested_names = "lowerCamelCase"
+)]
+pub enum Shape {
+	Circle { radius: Value },
+	Rectangle { width: Value, corner_radius: Value },
+	Empty,
+}
+
+#[faible(
+	json::AdjacentlyTagged::<Error>::new("t", "c"),
+	weak = Value,
+	strong = Map<String, Value>,
+	names = "verbatim",
+	nested_names = "verbatim"
+)]
+pub enum Message {
+	Text(#[faible(_, name = ())] Value),
+	Move { x: Value, y: Value },
+	Quit,
+}
+
+#[faible(
+	json::ExternallyTagged::<Error>::new(),
+	weak = Value,
+	strong = Value,
+	names = "verbatim",
+	nested_names = "verbatim",
+	no_weak_conversions
+)]
+pub enum Command {
+	Say(#[faible(_, name = ())] Value),
+	Move { x: Value, y: Value },
+	Stop,
+}
+
+#[faible(
+	json::StringUnit::<Error>::new(),
+	weak = Value,
+	strong = String,
+	names = "lowerCamelCase",
+	set_variant
+)]
+pub enum Direction {
+	North,
+	East,
+	South,
+	West,
+}
+
+#[faible(
+	json::Untagged::<Error>::new(),
+	weak = Value,
+	strong = Value,
+	names = "verbatim",
+	nested_names = "verbatim",
+	no_weak_conversions
+)]
+pub enum Coordinate {
+	Scalar(#[faible(_, name = ())] Number),
+	Point { x: Number, y: Number },
+	Other(#[faible(_, name = ())] Value),
+}
+
+#[faible(
+	json::Untagged::<Error>::strict(),
+	weak = Value,
+	strong = Value,
+	names = "verbatim",
+	nested_names = "verbatim",
+	no_weak_conversions
+)]
+pub enum Label {
+	Text(#[faible(_, name = ())] String),
+	Named { name: String },
+	Numbered { number: Number },
+}
+
+#[faible(
+	json::InternallyTagged::<Error>::new("type"),
+	weak = Value,
+	strong = Map<String, Value>,
+	names = "snake_case",
+	nested_names = "snake_case"
+)]
+pub enum Event {
+	Created {
+		id: Value,
+	},
+	Deleted {
+		id: Value,
+	},
+	#[faible(other)]
+	Unknown(Map<String, Value>),
+}
+
+#[faible(
+	json::StringUnit::<Error>::new(),
+	weak = Value,
+	strong = String,
+	names = "lowerCamelCase"
+)]
+pub enum Status {
+	Active,
+	#[faible(other = weak)]
+	Unknown {
+		raw: Value,
+	},
+}
+
+#[faible(
+	json::InternallyTagged::<Error>::new("type"),
+	weak = Value,
+	strong = Map<String, Value>,
+	names = "snake_case",
+	nested_names = "lowerCamelCase"
+)]
+pub enum Notification {
+	#[faible(common)]
+	Common {
+		pub id: Value,
+		pub sent_at: Value,
+	},
+	Mention {
+		user: Value,
+	},
+	Reminder,
+}
+
+#[faible(
+	json::Object::<Error>::new(),
+	weak = Value,
+	strong = Map<String, Value>,
+	names = "lowerCamelCase"
+)]
+pub struct MapInfo {
+	pub tile_width: Value,
+}
+
+#[faible(
+	json::InternallyTagged::<Error>::new("type"),
+	weak = Value,
+	strong = Map<String, Value>,
+	names = "snake_case",
+	nested_names = "lowerCamelCase"
+)]
+pub enum Asset {
+	Map(#[faible(view)] MapInfo),
+	Image { path: Value },
+}
+
+#[faible(
+	json::InternallyTagged::<Error>::new("type"),
+	weak = Value,
+	strong = Map<String, Value>,
+	names = "snake_case"
+)]
+pub enum Signal {
+	Ping,
+	Pong,
+}
+
+#[faible(
+	json::IntegerUnit::<Error>::new(),
+	weak = Value,
+	strong = Number,
+	names = discriminant,
+	set_variant
+)]
+#[derive(Debug, PartialEq)]
+pub enum Priority {
+	Low = 1,
+	Normal = 2,
+	High = 3,
+	Urgent = -1,
+}
+
+#[test]
+fn internally_tagged() {
+	let mut shape = Shape::from(json!({ "type": "rectangle", "width": 2, "cornerRadius": 0.5 }));
+	assert!(matches!(
+		shape.as_variant().unwrap(),
+		ShapeVariantRef::Rectangle { width, corner_radius }
+			if width == &json!(2) && corner_radius == &json!(0.5)
+	));
+
+	match shape.as_variant_mut().unwrap() {
+		ShapeVariantMut::Rectangle {
+			width,
+			corner_radius,
+		} => {
+			*width = json!(3);
+			*corner_radius = json!(0);
+		}
+		_ => unreachable!(),
+	}
+	assert_eq!(
+		shape.0,
+		json!({ "type": "rectangle", "width": 3, "cornerRadius": 0 })
+	);
+
+	let empty = Shape::from(json!({ "type": "empty" }));
+	assert!(matches!(empty.as_variant(), Ok(ShapeVariantRef::Empty)));
+
+	let unknown = Shape::from(json!({ "type": "triangle" }));
+	assert_eq!(unknown.as_variant().err(), Some(Error::NoVariantRecognized));
+
+	let untagged = Shape::from(json!({ "radius": 1 }));
+	assert_eq!(
+		untagged.as_variant().err(),
+		Some(Error::MissingField("\"type\"".to_string()))
+	);
+
+	let incomplete = Shape::from(json!({ "type": "circle" }));
+	assert_eq!(
+		incomplete.as_variant().err(),
+		Some(Error::MissingField("\"radius\"".to_string()))
+	);
+}
+
+#[test]
+fn adjacently_tagged() {
+	let mut message = Message::from(json!({ "t": "Move", "c": { "x": 1, "y": 2 } }));
+	assert!(matches!(
+		message.as_variant().unwrap(),
+		MessageVariantRef::Move { x, y } if x == &json!(1) && y == &json!(2)
+	));
+
+	if let MessageVariantMut::Move { x, y } = message.as_variant_mut().unwrap() {
+		std::mem::swap(x, y);
+	}
+	assert_eq!(message.0, json!({ "t": "Move", "c": { "x": 2, "y": 1 } }));
+
+	let mut text = Message::from(json!({ "t": "Text", "c": "hello" }));
+	if let MessageVariantMut::Text(content) = text.as_variant_mut().unwrap() {
+		*content = json!("bye");
+	}
+	assert!(matches!(
+		text.as_variant().unwrap(),
+		MessageVariantRef::Text(content) if content == "bye"
+	));
+
+	let quit = Message::from(json!({ "t": "Quit" }));
+	assert!(matches!(quit.as_variant(), Ok(MessageVariantRef::Quit)));
+}
+
+#[test]
+fn externally_tagged() {
+	let mut command = Command::from(json!({ "Move": { "x": 1, "y": 2 } }));
+	if let CommandVariantMut::Move { x, .. } = command.as_variant_mut().unwrap() {
+		*x = json!(5);
+	}
+	assert_eq!(command.0, json!({ "Move": { "x": 5, "y": 2 } }));
+
+	let say = Command::from(json!({ "Say": "hi" }));
+	assert!(matches!(
+		say.as_variant().unwrap(),
+		CommandVariantRef::Say(content) if content == "hi"
+	));
+
+	let stop = Command::from(json!("Stop"));
+	assert!(matches!(stop.as_variant(), Ok(CommandVariantRef::Stop)));
+
+	let ambiguous = Command::from(json!({ "Say": "hi", "Stop": null }));
+	assert_eq!(
+		ambiguous.as_variant().err(),
+		Some(Error::TypeMismatch(
+			"single-field object or string".to_string()
+		))
+	);
+
+	let mut unknown = Command::from(json!("Jump"));
+	assert_eq!(
+		unknown.as_variant_mut().err(),
+		Some(Error::NoVariantRecognized)
+	);
+}
+
+#[test]
+fn string_units() {
+	let mut direction = Direction::from(json!("south"));
+	assert!(matches!(
+		direction.as_variant(),
+		Ok(DirectionVariantRef::South)
+	));
+	assert!(matches!(
+		direction.as_variant_mut(),
+		Ok(DirectionVariantMut::South)
+	));
+
+	let number = Direction::from(json!(0));
+	assert_eq!(
+		number.as_variant().err(),
+		Some(Error::TypeMismatch("string".to_string()))
+	);
+}
+
+#[test]
+fn untagged() {
+	let scalar = Coordinate::from(json!(1.5));
+	assert!(matches!(
+		scalar.as_variant().unwrap(),
+		CoordinateVariantRef::Scalar(number) if number.as_f64() == Some(1.5)
+	));
+
+	let mut point = Coordinate::from(json!({ "x": 1, "y": 2 }));
+	assert!(matches!(
+		point.as_variant().unwrap(),
+		CoordinateVariantRef::Point { x, y } if x.as_u64() == Some(1) && y.as_u64() == Some(2)
+	));
+	if let CoordinateVariantMut::Point { x, .. } = point.as_variant_mut().unwrap() {
+		*x = 3.into();
+	}
+	assert_eq!(point.0, json!({ "x": 3, "y": 2 }));
+
+	let mut other = Coordinate::from(json!({ "x": 1, "y": "2" }));
+	assert!(matches!(
+		other.as_variant().unwrap(),
+		CoordinateVariantRef::Other(_)
+	));
+	if let CoordinateVariantMut::Other(value) = other.as_variant_mut().unwrap() {
+		*value = json!(4);
+	}
+	assert!(matches!(
+		other.as_variant_mut().unwrap(),
+		CoordinateVariantMut::Scalar(_)
+	));
+}
+
+#[test]
+fn untagged_strict() {
+	let mut text = Label::from(json!("faible"));
+	assert!(matches!(
+		text.as_variant().unwrap(),
+		LabelVariantRef::Text(text) if text == "faible"
+	));
+	if let LabelVariantMut::Text(text) = text.as_variant_mut().unwrap() {
+		text.push('!');
+	}
+	assert_eq!(text.0, json!("faible!"));
+
+	let named = Label::from(json!({ "name": "first" }));
+	assert!(matches!(
+		named.as_variant().unwrap(),
+		LabelVariantRef::Named { name } if name == "first"
+	));
+
+	let mut both = Label::from(json!({ "name": "first", "number": 1 }));
+	assert_eq!(both.as_variant().err(), Some(Error::AmbiguousVariant));
+	assert_eq!(both.as_variant_mut().err(), Some(Error::AmbiguousVariant));
+
+	let mut neither = Label::from(json!(null));
+	assert_eq!(neither.as_variant().err(), Some(Error::NoVariantRecognized));
+	assert_eq!(
+		neither.as_variant_mut().err(),
+		Some(Error::NoVariantRecognized)
+	);
+}
+
+#[test]
+fn other() {
+	let created = Event::from(json!({ "type": "created", "id": 1 }));
+	assert!(matches!(
+		created.as_variant().unwrap(),
+		EventVariantRef::Created { id } if id == &json!(1)
+	));
+
+	let mut renamed = Event::from(json!({ "type": "renamed", "id": 1, "name": "new" }));
+	assert!(matches!(
+		renamed.as_variant().unwrap(),
+		EventVariantRef::Unknown(event) if event["name"] == "new"
+	));
+	if let EventVariantMut::Unknown(event) = renamed.as_variant_mut().unwrap() {
+		event.remove("name");
+	}
+	assert_eq!(renamed.0, json!({ "type": "renamed", "id": 1 }));
+
+	// The other variant also catches values that fail to classify.
+	let untagged = Event::from(json!({ "id": 1 }));
+	assert!(matches!(
+		untagged.as_variant().unwrap(),
+		EventVariantRef::Unknown(event) if event["id"] == 1
+	));
+	assert_eq!(untagged.variant(), Ok(EventVariantKind::Unknown));
+	assert_eq!(untagged.is_created(), Ok(false));
+
+	let mut status = Status::from(json!("paused"));
+	assert!(matches!(
+		status.as_variant().unwrap(),
+		StatusVariantRef::Unknown { raw } if raw == "paused"
+	));
+	if let StatusVariantMut::Unknown { raw } = status.as_variant_mut().unwrap() {
+		*raw = json!("active");
+	}
+	assert!(matches!(status.as_variant(), Ok(StatusVariantRef::Active)));
+
+	// A weak other variant also catches values without a strong representation.
+	let mut number = Status::from(json!(1));
+	assert!(matches!(
+		number.as_variant().unwrap(),
+		StatusVariantRef::Unknown { raw } if raw == 1
+	));
+	assert_eq!(number.variant(), Ok(StatusVariantKind::Unknown));
+	assert_eq!(number.is_active(), Ok(false));
+	assert!(matches!(
+		number.as_variant_mut().unwrap(),
+		StatusVariantMut::Unknown { raw } if *raw == 1
+	));
+}
+
+#[test]
+fn variant_accessors() {
+	let mut point = Coordinate::from(json!({ "x": 1, "y": 2 }));
+	assert_eq!(point.is_scalar(), Ok(false));
+	assert_eq!(point.is_point(), Ok(true));
+	assert_eq!(point.is_other(), Ok(false));
+	assert_eq!(point.as_scalar(), Ok(None));
+	assert_eq!(point.as_other(), Ok(None));
+	assert_eq!(point.as_other_mut(), Ok(None));
+	assert_eq!(
+		point.as_point(),
+		Ok(Some((&Number::from(1), &Number::from(2))))
+	);
+
+	if let Ok(Some((_, y))) = point.as_point_mut() {
+		*y = 5.into();
+	}
+	assert_eq!(point.0, json!({ "x": 1, "y": 5 }));
+	assert_eq!(point.as_scalar_mut(), Ok(None));
+
+	let mut event = Event::from(json!({ "type": "renamed", "id": 1 }));
+	assert_eq!(event.is_created(), Ok(false));
+	assert_eq!(event.is_unknown(), Ok(true));
+	if let Ok(Some(event)) = event.as_unknown_mut() {
+		event.insert("type".to_string(), json!("created"));
+	}
+	assert_eq!(event.is_unknown(), Ok(false));
+	assert_eq!(event.as_created(), Ok(Some(&json!(1))));
+	assert_eq!(event.as_unknown(), Ok(None));
+
+	let mut scalar = Coordinate::from(json!(3));
+	assert_eq!(scalar.is_scalar(), Ok(true));
+	assert_eq!(scalar.is_other(), Ok(false));
+	assert_eq!(scalar.as_other(), Ok(None));
+	assert_eq!(scalar.as_other_mut(), Ok(None));
+
+	let mut both = Label::from(json!({ "name": "first", "number": 1 }));
+	assert_eq!(both.is_named(), Err(Error::AmbiguousVariant));
+	assert_eq!(both.as_numbered(), Err(Error::AmbiguousVariant));
+	assert_eq!(both.as_named_mut(), Err(Error::AmbiguousVariant));
+	assert_eq!(both.is_text(), Ok(false));
+}
+
+#[test]
+fn variant() {
+	let shape = Shape::from(json!({ "type": "circle" }));
+	assert_eq!(shape.variant(), Ok(ShapeVariantKind::Circle));
+	assert_eq!(
+		format!("{:?}", ShapeVariantKind::Circle.weak_name()),
+		"Some(\"circle\")"
+	);
+
+	let both = Label::from(json!({ "name": "first", "number": 1 }));
+	assert_eq!(both.variant(), Err(Error::AmbiguousVariant));
+	let numbered = Label::from(json!({ "number": 1 }));
+	assert_eq!(numbered.variant(), Ok(LabelVariantKind::Numbered));
+
+	let renamed = Event::from(json!({ "type": "renamed" }));
+	assert_eq!(renamed.variant(), Ok(EventVariantKind::Unknown));
+	assert!(EventVariantKind::Unknown.weak_name().is_none());
+
+	let paused = Status::from(json!("paused"));
+	assert_eq!(paused.variant(), Ok(StatusVariantKind::Unknown));
+}
+
+#[test]
+fn common() {
+	let mut mention =
+		Notification::from(json!({ "type": "mention", "id": 1, "sentAt": 2, "user": "faible" }));
+	assert_eq!(mention.id(), Ok(&json!(1)));
+	assert_eq!(mention.sent_at(), Ok(&json!(2)));
+	assert_eq!(mention.variant(), Ok(NotificationVariantKind::Mention));
+
+	*mention.id_mut().unwrap() = json!(3);
+	assert_eq!(
+		mention.insert_sent_at(json!(4)),
+		Ok((&mut json!(4), Some(json!(2))))
+	);
+	assert_eq!(
+		mention.0,
+		json!({ "type": "mention", "id": 3, "sentAt": 4, "user": "faible" })
+	);
+
+	let mut reminder = Notification::from(json!({ "type": "reminder" }));
+	assert_eq!(
+		reminder.id(),
+		Err(Error::MissingField("\"id\"".to_string()))
+	);
+	reminder.set_id(json!(5)).unwrap();
+	assert_eq!(reminder.0, json!({ "type": "reminder", "id": 5 }));
+	assert!(matches!(
+		reminder.as_variant(),
+		Ok(NotificationVariantRef::Reminder)
+	));
+}
+
+#[test]
+fn views() {
+	let mut map = Asset::from(json!({ "type": "map", "tileWidth": 16 }));
+	assert!(matches!(
+		map.as_variant().unwrap(),
+		AssetVariantRef::Map(info) if info.tile_width() == Ok(&json!(16))
+	));
+	assert_eq!(map.variant(), Ok(AssetVariantKind::Map));
+	assert!(map.is_map().unwrap());
+
+	if let AssetVariantMut::Map(info) = map.as_variant_mut().unwrap() {
+		info.set_tile_width(json!(32)).unwrap();
+	}
+	assert_eq!(map.0, json!({ "type": "map", "tileWidth": 32 }));
+	map.as_map_mut()
+		.unwrap()
+		.unwrap()
+		.set_tile_width(json!(8))
+		.unwrap();
+	assert_eq!(map.as_map().unwrap().unwrap().tile_width(), Ok(&json!(8)));
+
+	let mut image = Asset::from(json!({ "type": "image", "path": "tiles.png" }));
+	assert!(matches!(
+		image.as_variant_mut().unwrap(),
+		AssetVariantMut::Image { path } if path == "tiles.png"
+	));
+	assert!(image.as_map().unwrap().is_none());
+}
+
+#[test]
+fn c_like() {
+	let mut priority = Priority::from(json!(3));
+	assert_eq!(priority.to_rust_enum(), Ok(PriorityVariantOwned::High));
+	priority.set_variant(PriorityVariantOwned::Urgent).unwrap();
+	assert_eq!(priority.0, json!(-1));
+	assert_eq!(priority.variant(), Ok(PriorityVariantKind::Urgent));
+
+	let unknown = Priority::from(json!(4));
+	assert_eq!(unknown.to_rust_enum(), Err(Error::NoVariantRecognized));
+	let fractional = Priority::from(json!(1.5));
+	assert_eq!(fractional.to_rust_enum(), Err(Error::NoVariantRecognized));
+
+	let mut direction = Direction::from(json!("north"));
+	assert!(matches!(
+		direction.to_rust_enum(),
+		Ok(DirectionVariantOwned::North)
+	));
+	direction.set_variant(DirectionVariantOwned::West).unwrap();
+	assert_eq!(direction.0, json!("west"));
+}
+
+#[test]
+fn tagged_units() {
+	let signal = Signal::from(json!({ "type": "pong" }));
+	assert_eq!(signal.variant(), Ok(SignalVariantKind::Pong));
+	assert!(matches!(signal.as_variant(), Ok(SignalVariantRef::Pong)));
+	assert!(matches!(
+		signal.to_rust_enum(),
+		Ok(SignalVariantOwned::Pong)
+	));
+	assert_eq!(signal.is_ping(), Ok(false));
+}
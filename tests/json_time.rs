@@ -0,0 +1,98 @@
+#![cfg(all(feature = "serde_json", feature = "time"))]
+
+use faible::{faible, json};
+use serde_json::{json, Map, Value};
+use time::{
+	format_description::well_known::Rfc3339,
+	macros::{datetime, format_description},
+	OffsetDateTime,
+};
+
+mod common;
+use common::Error;
+
+#[faible(
+	json::Object::<Error>::new(),
+	weak = Value,
+	strong = Map<String, Value>,
+	names = "lowerCamelCase"
+)]
+pub struct Event {
+	#[faible(json::DateTime::<_, Error>::new(Rfc3339), by_value)]
+	pub created_at: OffsetDateTime,
+	#[faible(
+		json::DateTime::<_, Error>::new(format_description!(
+			"[year]-[month]-[day] [hour]:[minute] [offset_hour sign:mandatory][offset_minute]"
+		)),
+		by_value
+	)]
+	pub scheduled_for: OffsetDateTime,
+	#[faible(json::UnixTimestamp::<Error>::seconds(), by_value)]
+	pub updated: OffsetDateTime,
+	#[faible(json::UnixTimestamp::<Error>::millis(), by_value)]
+	pub seen: OffsetDateTime,
+}
+
+#[test]
+fn read() {
+	let event = Event::from(json!({
+		"createdAt": "2022-05-01T12:30:00.25-04:00",
+		"scheduledFor": "2022-06-01 09:00 +0200",
+		"updated": 1651408200,
+		"seen": -1,
+	}));
+	assert_eq!(event.created_at(), Ok(datetime!(2022-05-01 12:30:00.25 -4)));
+	assert_eq!(event.scheduled_for(), Ok(datetime!(2022-06-01 09:00 +2)));
+	assert_eq!(event.updated(), Ok(datetime!(2022-05-01 12:30 UTC)));
+	assert_eq!(event.seen(), Ok(datetime!(1969-12-31 23:59:59.999 UTC)));
+}
+
+#[test]
+fn write_keeps_style() {
+	let mut event = Event::from(json!({}));
+	event
+		.set_created_at(datetime!(2022-05-01 12:30:00.25 -4))
+		.unwrap();
+	event
+		.set_scheduled_for(datetime!(2022-06-01 09:00 +2))
+		.unwrap();
+	event
+		.set_updated(datetime!(2022-05-01 12:30:59.9 UTC))
+		.unwrap();
+	event
+		.set_seen(datetime!(1969-12-31 23:59:59.9995 UTC))
+		.unwrap();
+	assert_eq!(
+		event.0,
+		json!({
+			"createdAt": "2022-05-01T12:30:00.25-04:00",
+			"scheduledFor": "2022-06-01 09:00 +0200",
+			"updated": 1651408259,
+			"seen": -1,
+		})
+	);
+}
+
+#[test]
+fn invalid() {
+	let mut event = Event::from(json!({
+		"createdAt": "yesterday",
+		"scheduledFor": 0,
+		"updated": 1e20,
+		"seen": 0.5,
+	}));
+	assert_eq!(
+		event.created_at(),
+		Err(Error::TypeMismatch("date-time string".to_string()))
+	);
+	assert_eq!(
+		event.scheduled_for(),
+		Err(Error::TypeMismatch("date-time string".to_string()))
+	);
+	assert_eq!(event.updated(), Err(Error::OutOfRange("i64".to_string())));
+	assert_eq!(event.seen(), Err(Error::OutOfRange("i64".to_string())));
+	assert_eq!(
+		event.set_created_at(datetime!(2022-05-01 12:30 +00:00:30)),
+		Err(Error::OutOfRange("formattable date-time".to_string()))
+	);
+}
@@ -0,0 +1,75 @@
+#![cfg(feature = "serde_json")]
+
+use faible::{faible, json};
+use serde_json::{json, Number, Value};
+use std::fmt::Debug;
+
+mod common;
+use common::Error;
+
+#[faible(
+	json::Union::<Error>::new(),
+	weak = Value,
+	strong = Value,
+	names = "verbatim",
+	no_weak_conversions
+)]
+pub union IdOrName {
+	pub id: Number,
+	pub name: String,
+}
+
+#[test]
+fn get() {
+	let id = IdOrName::from(json!(7));
+	assert_eq!(id.id(), Ok(Some(&Number::from(7))));
+	assert_eq!(id.name(), Ok(None));
+
+	let name = IdOrName::from(json!("faible"));
+	assert_eq!(name.id(), Ok(None));
+	assert_eq!(name.name().unwrap().map(String::as_str), Some("faible"));
+
+	let neither = IdOrName::from(json!(null));
+	assert_eq!(neither.id(), Ok(None));
+	assert_eq!(neither.name(), Ok(None));
+}
+
+#[test]
+fn set() {
+	let mut user = IdOrName::from(json!(7));
+	assert_eq!(user.name_mut(), Ok(None));
+
+	user.set_name("faible".to_string()).unwrap();
+	assert_eq!(user.0, json!("faible"));
+	user.name_mut().unwrap().unwrap().push('!');
+	assert_eq!(user.0, json!("faible!"));
+
+	let (id, prev) = user.insert_id(Number::from(8)).unwrap();
+	assert_eq!(*id, Number::from(8));
+	assert_eq!(prev, None);
+
+	let (_, prev) = user.insert_id(Number::from(9)).unwrap();
+	assert_eq!(prev, Some(Number::from(8)));
+	assert_eq!(user.0, json!(9));
+}
+
+#[test]
+fn which() {
+	let id = IdOrName::from(json!(7));
+	assert_eq!(id.which(), Ok(Some(IdOrNameField::Id)));
+	assert!(matches!(
+		id.as_ref(),
+		Ok(Some(IdOrNameUnionRef::Id(id))) if id.as_u64() == Some(7)
+	));
+
+	let name = IdOrName::from(json!("faible"));
+	assert_eq!(name.which(), Ok(Some(IdOrNameField::Name)));
+	assert!(matches!(
+		name.as_ref(),
+		Ok(Some(IdOrNameUnionRef::Name(name))) if name == "faible"
+	));
+
+	let neither = IdOrName::from(json!([]));
+	assert_eq!(neither.which(), Ok(None));
+	assert!(matches!(neither.as_ref(), Ok(None)));
+}
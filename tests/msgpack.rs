@@ -0,0 +1,136 @@
+#![cfg(feature = "rmpv")]
+
+use faible::{faible, msgpack};
+use rmpv::Value;
+use std::fmt::Debug;
+
+mod common;
+use common::Error;
+
+#[faible(
+	msgpack::Map::<Error>::new(),
+	weak = Value,
+	strong = Vec<(Value, Value)>,
+	names = "snake_case"
+)]
+pub struct Player {
+	pub name: Value,
+	pub stats: Stats,
+	pub position: Position,
+}
+
+#[faible(
+	msgpack::Map::<Error>::new(),
+	weak = Value,
+	strong = Vec<(Value, Value)>,
+	names = index
+)]
+pub struct Stats {
+	pub score: Value,
+	pub level: Value,
+}
+
+#[faible(
+	msgpack::Array::<Error>::new(),
+	weak = Value,
+	strong = Vec<Value>,
+	names = index
+)]
+pub struct Position {
+	pub x: Value,
+	pub y: Value,
+}
+
+#[faible(
+	msgpack::Ext::<Error>::new(),
+	weak = Value,
+	strong = Value,
+	names = index,
+	nested_names = (),
+	no_weak_conversions
+)]
+pub enum Extension {
+	#[faible(_, name = 1)]
+	Timestamp(Vec<u8>),
+	#[faible(_, name = 2)]
+	Blob(Vec<u8>),
+}
+
+fn player() -> Player {
+	Player::from(Value::Map(vec![
+		(Value::from("name"), Value::from("Ferris")),
+		(
+			Value::from("stats"),
+			Value::Map(vec![(Value::from(0), Value::from(42))]),
+		),
+		(
+			Value::from("position"),
+			Value::Array(vec![Value::from(1), Value::from(2)]),
+		),
+	]))
+}
+
+#[test]
+fn string_and_integer_keys() {
+	let player = player();
+	assert_eq!(player.name().unwrap(), &Value::from("Ferris"));
+	assert_eq!(player.stats().unwrap().score().unwrap(), &Value::from(42));
+}
+
+#[test]
+fn array_elements() {
+	let mut player = player();
+	assert_eq!(player.position().unwrap().y().unwrap(), &Value::from(2));
+
+	let position = player.position_mut().unwrap();
+	position.set_x(Value::from(3)).unwrap();
+	assert_eq!(position.x().unwrap(), &Value::from(3));
+}
+
+#[test]
+fn set_appends() {
+	let mut stats = Stats::from(Value::Map(vec![]));
+	assert_eq!(
+		stats.level().unwrap_err(),
+		Error::MissingField("1".to_string())
+	);
+
+	stats.set_level(Value::from(1)).unwrap();
+	let (level, prev) = stats.insert_level(Value::from(2)).unwrap();
+	assert_eq!(level, &Value::from(2));
+	assert_eq!(prev, Some(Value::from(1)));
+	assert_eq!(
+		Value::from(stats),
+		Value::Map(vec![(Value::from(1), Value::from(2))])
+	);
+}
+
+#[test]
+fn type_mismatch() {
+	let player = Player::from(Value::Nil);
+	assert_eq!(
+		player.name().unwrap_err(),
+		Error::TypeMismatch("map".to_string())
+	);
+}
+
+#[test]
+fn ext_variants() {
+	let mut extension = Extension::from(Value::Ext(2, vec![1, 2, 3]));
+	assert!(matches!(
+		extension.as_variant().unwrap(),
+		ExtensionVariantRef::Blob(data) if data == &[1, 2, 3]
+	));
+
+	match extension.as_variant_mut().unwrap() {
+		ExtensionVariantMut::Blob(data) => data.push(4),
+		ExtensionVariantMut::Timestamp(_) => unreachable!(),
+	}
+	assert_eq!(extension.0, Value::Ext(2, vec![1, 2, 3, 4]));
+
+	let extension = Extension::from(Value::Ext(3, vec![]));
+	assert_eq!(
+		extension.as_variant().err(),
+		Some(Error::NoVariantRecognized)
+	);
+}